@@ -1,3 +1,18 @@
 pub mod list;
+pub mod list_pool;
+pub mod persistent_list;
+pub mod pointer_family;
+pub mod skip_list;
+pub mod sorted_list;
+pub mod unrolled_list;
 
-pub use list::List;
\ No newline at end of file
+#[cfg(test)]
+mod test_support;
+
+pub use list::{List, ListCorruption, ListError};
+pub use list_pool::ListPool;
+pub use persistent_list::PersistentList;
+pub use pointer_family::{ArcFamily, BoxFamily, PointerFamily, RcFamily, SharedPointerFamily};
+pub use skip_list::SkipList;
+pub use sorted_list::SortedList;
+pub use unrolled_list::UnrolledList;
@@ -0,0 +1,149 @@
+//! Slot management and linking of the arena entries, shared by [`List`](super::List)
+//! and [`ListPool`](crate::ListPool). Both keep their nodes as [`ListEntry`]s and
+//! their chains with self loops at both ends, the functions only differ in where
+//! head and tail of a chain are stored.
+
+use std::collections::VecDeque;
+
+use super::{ListEntry, NodeHandle, SlotData};
+
+/// A generation no slot of the arena had before, taken from its counter.
+pub(crate) fn fresh_generation(next_generation: &mut usize) -> usize {
+    let generation = *next_generation;
+    *next_generation = next_generation.wrapping_add(1);
+    generation
+}
+
+/// Stores the element in a free slot (or a new one with a fresh generation)
+/// whose links point to itself. Linking it into a chain is left to the caller.
+pub(crate) fn occupy_slot<P>(
+    all_elements: &mut Vec<ListEntry<P>>,
+    free_indices: &mut VecDeque<usize>,
+    next_generation: &mut usize,
+    element: P,
+) -> usize {
+    match free_indices.pop_front() {
+        Some(free_index) => {
+            let free_entry = &mut all_elements[free_index];
+            free_entry.hold_data = SlotData::Occupied(element);
+            free_entry.next_index = free_index;
+            free_entry.prev_index = free_index;
+            free_index
+        }
+        None => {
+            let new_index = all_elements.len();
+            all_elements.push(ListEntry {
+                hold_data: SlotData::Occupied(element),
+                next_index: new_index,
+                prev_index: new_index,
+                generation: fresh_generation(next_generation),
+            });
+            new_index
+        }
+    }
+}
+
+/// Takes the element out of a slot that is no longer linked into a chain. The
+/// slot gets a fresh generation, so handles to it are stale.
+pub(crate) fn vacate_slot<P>(
+    all_elements: &mut [ListEntry<P>],
+    free_indices: &mut VecDeque<usize>,
+    next_generation: &mut usize,
+    slot_index: usize,
+) -> P {
+    let freed_entry = &mut all_elements[slot_index];
+    freed_entry.generation = fresh_generation(next_generation);
+    let retr_data = std::mem::replace(&mut freed_entry.hold_data, SlotData::Vacant);
+    free_indices.push_back(slot_index);
+
+    match retr_data {
+        SlotData::Occupied(hold_data) => hold_data,
+        SlotData::Vacant => unreachable!("vacant slot linked into the list"),
+    }
+}
+
+/// Returns the slot of the handle if it is occupied by the node the handle
+/// was issued for.
+pub(crate) fn resolve_slot<P>(all_elements: &[ListEntry<P>], handle: NodeHandle) -> Option<usize> {
+    all_elements
+        .get(handle.slot_index)
+        .filter(|entry| entry.is_occupied() && entry.generation == handle.generation)
+        .map(|_| handle.slot_index)
+}
+
+pub(crate) fn handle_of<P>(all_elements: &[ListEntry<P>], slot_index: usize) -> NodeHandle {
+    NodeHandle {
+        slot_index,
+        generation: all_elements[slot_index].generation,
+    }
+}
+
+/// Links the unlinked slot `new_index` behind `anchor_index`, which is part
+/// of the chain ending at `tail_index`.
+pub(crate) fn link_after<P>(
+    all_elements: &mut [ListEntry<P>],
+    tail_index: &mut usize,
+    anchor_index: usize,
+    new_index: usize,
+) {
+    if anchor_index == *tail_index {
+        // the new entry becomes the tail and loops forward to itself
+        *tail_index = new_index;
+        all_elements[new_index].next_index = new_index;
+    } else {
+        let next_index = all_elements[anchor_index].next_index;
+        all_elements[new_index].next_index = next_index;
+        all_elements[next_index].prev_index = new_index;
+    }
+    all_elements[new_index].prev_index = anchor_index;
+    all_elements[anchor_index].next_index = new_index;
+}
+
+/// Links the unlinked slot `new_index` in front of `anchor_index`, which is
+/// part of the chain starting at `head_index`.
+pub(crate) fn link_before<P>(
+    all_elements: &mut [ListEntry<P>],
+    head_index: &mut usize,
+    anchor_index: usize,
+    new_index: usize,
+) {
+    if anchor_index == *head_index {
+        // the new entry becomes the head and loops back to itself
+        *head_index = new_index;
+        all_elements[new_index].prev_index = new_index;
+    } else {
+        let prev_index = all_elements[anchor_index].prev_index;
+        all_elements[new_index].prev_index = prev_index;
+        all_elements[prev_index].next_index = new_index;
+    }
+    all_elements[new_index].next_index = anchor_index;
+    all_elements[anchor_index].prev_index = new_index;
+}
+
+/// Detaches the slot from its chain, the element stays in the slot. Head and
+/// tail are meaningless afterwards if the slot was the only one.
+pub(crate) fn unlink_slot<P>(
+    all_elements: &mut [ListEntry<P>],
+    head_index: &mut usize,
+    tail_index: &mut usize,
+    slot_index: usize,
+) {
+    let prev_index = all_elements[slot_index].prev_index;
+    let next_index = all_elements[slot_index].next_index;
+
+    match (slot_index == *head_index, slot_index == *tail_index) {
+        (true, true) => {}
+        (true, false) => {
+            *head_index = next_index;
+            all_elements[next_index].prev_index = next_index;
+        }
+        (false, true) => {
+            *tail_index = prev_index;
+            all_elements[prev_index].next_index = prev_index;
+        }
+        (false, false) => {
+            all_elements[prev_index].next_index = next_index;
+            all_elements[next_index].prev_index = prev_index;
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use super::{List, ListError, SlotRemap};
+use crate::pointer_family::PointerFamily;
+
+impl<T: ?Sized, F: PointerFamily> List<T, F> {
+    /// Number of elements the list can hold without allocating, vacant slots
+    /// included.
+    pub fn capacity(&self) -> usize {
+        self.all_elements.capacity()
+    }
+
+    /// Reserves room for at least `additional` more elements. Vacant slots are
+    /// reused first, so the arena only grows by what they can't hold.
+    ///
+    /// ```
+    /// use container::List;
+    ///
+    /// let mut linked_list = List::from_array([1, 2, 3]);
+    /// linked_list.remove_at(0);
+    /// linked_list.reserve(10);
+    ///
+    /// assert!(linked_list.capacity() >= 12);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.all_elements.reserve(self.missing_slots(additional));
+    }
+
+    /// Like [`reserve`](List::reserve), but doesn't over-allocate on purpose.
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.all_elements
+            .reserve_exact(self.missing_slots(additional));
+    }
+
+    /// Like [`reserve`](List::reserve), but reports a failed allocation as
+    /// [`ListError::CapacityOverflow`] instead of aborting.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), ListError> {
+        self.all_elements
+            .try_reserve(self.missing_slots(additional))
+            .map_err(|_| ListError::CapacityOverflow)
+    }
+
+    /// Releases all memory not needed for the current elements.
+    ///
+    /// Vacant slots can only be given back by compacting the arena, which
+    /// makes all [`NodeHandle`](super::NodeHandle)s stale, the returned
+    /// [`SlotRemap`] translates them.
+    pub fn shrink_to_fit(&mut self) -> SlotRemap {
+        let slot_remap = self.compact();
+        self.free_indices.shrink_to_fit();
+        slot_remap
+    }
+
+    fn missing_slots(&self, additional: usize) -> usize {
+        additional.saturating_sub(self.free_indices.len())
+    }
+}
@@ -0,0 +1,111 @@
+use super::{List, ListError};
+use crate::pointer_family::{PointerFamily, SharedPointerFamily};
+
+impl<T: ?Sized, F: PointerFamily> List<T, F> {
+    /// Returns a reference to the element at the index.
+    ///
+    /// ```
+    /// use container::List;
+    /// use container::list::ListError;
+    ///
+    /// let linked_list = List::from_array([1, 2]);
+    ///
+    /// assert_eq!(linked_list.try_get(1), Ok(&2));
+    /// assert_eq!(linked_list.try_get(2), Err(ListError::OutOfRange { index: 2, len: 2 }));
+    /// ```
+    pub fn try_get(&self, index: usize) -> Result<&T, ListError> {
+        let slot = self.try_slot_at(index)?;
+        Ok(&**self.all_elements[slot].data())
+    }
+
+    pub fn try_at(&self, index: usize) -> Result<F::Pointer<T>, ListError>
+    where
+        F: SharedPointerFamily,
+    {
+        let slot = self.try_slot_at(index)?;
+        Ok(F::clone_pointer(self.all_elements[slot].data()))
+    }
+
+    /// Returns a mutable reference to the element at the index, cloning it
+    /// first if it is shared with another pointer.
+    pub fn try_make_mut(&mut self, index: usize) -> Result<&mut T, ListError>
+    where
+        T: Clone,
+    {
+        let slot = self.slot_at_mut(index).ok_or(ListError::OutOfRange {
+            index,
+            len: self.len(),
+        })?;
+        Ok(F::make_mut(self.all_elements[slot].data_mut()))
+    }
+
+    /// Inserts the element in front of the element at the index, an index equal
+    /// to the length appends it.
+    pub fn try_insert_before(&mut self, insert_index: usize, element: T) -> Result<(), ListError>
+    where
+        T: Sized,
+    {
+        self.try_insert_before_shared(insert_index, F::new(element))
+    }
+
+    pub fn try_insert_before_shared(
+        &mut self,
+        insert_index: usize,
+        element: F::Pointer<T>,
+    ) -> Result<(), ListError> {
+        self.try_link_at(insert_index, element).map(|_| ())
+    }
+
+    pub fn try_remove_at(&mut self, index: usize) -> Result<F::Pointer<T>, ListError> {
+        let slot = self.try_slot_at(index)?;
+        let removed_data = self.unlink(slot);
+        self.apply_compaction_policy();
+        Ok(removed_data)
+    }
+
+    pub fn try_pop_front(&mut self) -> Result<F::Pointer<T>, ListError> {
+        self.pop_front().ok_or(ListError::EmptyList)
+    }
+
+    pub fn try_pop_back(&mut self) -> Result<F::Pointer<T>, ListError> {
+        self.pop_back().ok_or(ListError::EmptyList)
+    }
+
+    fn try_slot_at(&self, index: usize) -> Result<usize, ListError> {
+        self.slot_at(index).ok_or(ListError::OutOfRange {
+            index,
+            len: self.len(),
+        })
+    }
+
+    /// Links the element in at the index and returns its slot. Growing the
+    /// arena is checked, so running out of memory is reported instead of aborting.
+    pub(super) fn try_link_at(
+        &mut self,
+        insert_index: usize,
+        element: F::Pointer<T>,
+    ) -> Result<usize, ListError> {
+        let list_len = self.len();
+        if insert_index > list_len {
+            return Err(ListError::OutOfRange {
+                index: insert_index,
+                len: list_len,
+            });
+        }
+        if self.free_indices.is_empty() {
+            self.all_elements
+                .try_reserve(1)
+                .map_err(|_| ListError::CapacityOverflow)?;
+        }
+
+        let new_index = match (insert_index == list_len, self.is_empty()) {
+            (_, true) => self.link_first(element),
+            (true, false) => self.link_after(self.tail_index, element),
+            (false, false) => {
+                let referenced_index = self.try_slot_at(insert_index)?;
+                self.link_before(referenced_index, element)
+            }
+        };
+        Ok(new_index)
+    }
+}
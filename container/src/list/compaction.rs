@@ -0,0 +1,201 @@
+use std::collections::VecDeque;
+
+use super::{List, ListEntry, NodeHandle, SlotData};
+use crate::pointer_family::PointerFamily;
+
+/// Decides when a [`List`] compacts its arena on its own.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum CompactionPolicy {
+    /// The arena is only compacted by calling [`List::compact`].
+    #[default]
+    Manual,
+    /// The arena is compacted after a removal through the list left more than
+    /// the given ratio (between 0 and 1) of the slots vacant.
+    VacancyRatio(f64),
+}
+
+/// Maps the slots of a [`List`] before a call to [`List::compact`] to the
+/// slots after it.
+///
+/// Compaction invalidates every [`NodeHandle`] of the list, [`remap`] translates
+/// a handle that was valid before the compaction into its new counterpart.
+///
+/// [`remap`]: SlotRemap::remap
+#[derive(Clone, Debug)]
+pub struct SlotRemap {
+    new_slots: Vec<Option<usize>>,
+    old_generations: Vec<usize>,
+    new_generation: usize,
+}
+
+impl SlotRemap {
+    /// Returns the new slot of the element stored in `old_slot`, `None` if the
+    /// slot was vacant.
+    pub fn get(&self, old_slot: usize) -> Option<usize> {
+        self.new_slots.get(old_slot).copied().flatten()
+    }
+
+    /// Iterates over all pairs of old and new slot of the stored elements.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.new_slots
+            .iter()
+            .enumerate()
+            .filter_map(|(old_slot, new_slot)| new_slot.map(|new_slot| (old_slot, new_slot)))
+    }
+
+    /// Combines this remap with one of a later compaction, so handles from
+    /// before this one are translated into the layout after `later`.
+    fn then(self, later: &SlotRemap) -> SlotRemap {
+        SlotRemap {
+            new_slots: self
+                .new_slots
+                .into_iter()
+                .map(|mid_slot| mid_slot.and_then(|mid_slot| later.get(mid_slot)))
+                .collect(),
+            old_generations: self.old_generations,
+            new_generation: later.new_generation,
+        }
+    }
+
+    pub fn remap(&self, handle: NodeHandle) -> Option<NodeHandle> {
+        let old_generation = self.old_generations.get(handle.slot_index)?;
+        if *old_generation != handle.generation {
+            return None;
+        }
+
+        self.get(handle.slot_index).map(|slot_index| NodeHandle {
+            slot_index,
+            generation: self.new_generation,
+        })
+    }
+}
+
+impl<T: ?Sized, F: PointerFamily> List<T, F> {
+    /// Rewrites the arena in list order, dropping all vacant slots and
+    /// releasing the memory they occupied.
+    ///
+    /// All existing [`NodeHandle`]s become stale, the returned [`SlotRemap`]
+    /// translates them to the new layout.
+    ///
+    /// ```
+    /// use container::List;
+    ///
+    /// let mut linked_list = List::from_array([0, 1, 2, 3]);
+    /// let handle = linked_list.handle_at(3).unwrap();
+    /// linked_list.remove_at(0);
+    /// linked_list.remove_at(0);
+    ///
+    /// let remap = linked_list.compact();
+    /// assert_eq!(linked_list.get_by_handle(handle), None);
+    ///
+    /// let new_handle = remap.remap(handle).unwrap();
+    /// assert_eq!(linked_list.get_by_handle(new_handle), Some(&3));
+    /// assert_eq!(new_handle.slot(), 1);
+    /// ```
+    pub fn compact(&mut self) -> SlotRemap {
+        let remap = self.compact_arena();
+        // a remap not yet taken has to lead into the new layout as well
+        if let Some(pending_remap) = self.pending_remap.take() {
+            self.pending_remap = Some(pending_remap.then(&remap));
+        }
+        remap
+    }
+
+    fn compact_arena(&mut self) -> SlotRemap {
+        let list_len = self.len();
+        let old_generations: Vec<usize> = self
+            .all_elements
+            .iter()
+            .map(|entry| entry.generation)
+            .collect();
+        // fresh from the counter, a generation derived from the current arena
+        // may already have been handed out for slots the arena lost
+        let new_generation = self.fresh_generation();
+
+        let mut old_elements = std::mem::take(&mut self.all_elements);
+        let mut new_slots = vec![None; old_elements.len()];
+        let mut compacted_elements = Vec::with_capacity(list_len);
+
+        let mut slot_index = self.head_index;
+        for new_index in 0..list_len {
+            let old_entry = &mut old_elements[slot_index];
+            new_slots[slot_index] = Some(new_index);
+            compacted_elements.push(ListEntry {
+                hold_data: std::mem::replace(&mut old_entry.hold_data, SlotData::Vacant),
+                next_index: usize::min(new_index + 1, list_len - 1),
+                prev_index: new_index.saturating_sub(1),
+                generation: new_generation,
+            });
+            slot_index = old_entry.next_index;
+        }
+
+        self.all_elements = compacted_elements;
+        self.free_indices = VecDeque::new();
+        self.head_index = 0;
+        self.tail_index = list_len.saturating_sub(1);
+        self.position_cache.invalidate();
+        self.check_invariants();
+
+        SlotRemap {
+            new_slots,
+            old_generations,
+            new_generation,
+        }
+    }
+
+    pub fn compaction_policy(&self) -> CompactionPolicy {
+        self.compaction_policy
+    }
+
+    /// Sets the policy for automatic compaction, see [`CompactionPolicy`].
+    ///
+    /// Automatic compaction happens in removals through the list itself (never
+    /// through a cursor) and makes all [`NodeHandle`]s stale, see
+    /// [`take_last_remap`](List::take_last_remap) to translate them.
+    pub fn set_compaction_policy(&mut self, policy: CompactionPolicy) {
+        self.compaction_policy = policy;
+        self.apply_compaction_policy();
+    }
+
+    /// Number of slots in the arena that currently hold no element.
+    pub fn vacant_slots(&self) -> usize {
+        self.free_indices.len()
+    }
+
+    /// Takes the [`SlotRemap`] of the automatic compactions since the last
+    /// call, `None` if the policy didn't compact the arena in the meantime.
+    ///
+    /// Several compactions are combined into one remap, which translates
+    /// handles issued before the first of them.
+    ///
+    /// ```
+    /// use container::List;
+    /// use container::list::CompactionPolicy;
+    ///
+    /// let mut linked_list = List::from_array([0, 1, 2, 3]);
+    /// linked_list.set_compaction_policy(CompactionPolicy::VacancyRatio(0.25));
+    /// let handle = linked_list.handle_at(3).unwrap();
+    /// linked_list.remove_at(0);
+    /// linked_list.remove_at(0);
+    ///
+    /// assert_eq!(linked_list.get_by_handle(handle), None);
+    /// let new_handle = linked_list.take_last_remap().unwrap().remap(handle).unwrap();
+    /// assert_eq!(linked_list.get_by_handle(new_handle), Some(&3));
+    /// ```
+    pub fn take_last_remap(&mut self) -> Option<SlotRemap> {
+        self.pending_remap.take()
+    }
+
+    pub(super) fn apply_compaction_policy(&mut self) {
+        if let CompactionPolicy::VacancyRatio(max_ratio) = self.compaction_policy {
+            let vacancy_ratio = self.free_indices.len() as f64 / self.all_elements.len() as f64;
+            if vacancy_ratio > max_ratio {
+                let remap = self.compact_arena();
+                self.pending_remap = Some(match self.pending_remap.take() {
+                    Some(pending_remap) => pending_remap.then(&remap),
+                    None => remap,
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use super::List;
+use crate::pointer_family::PointerFamily;
+
+impl<T: ?Sized, F: PointerFamily> List<T, F> {
+    /// Inserts the element in front of the head in constant time.
+    ///
+    /// ```
+    /// use container::List;
+    ///
+    /// let mut linked_list = List::from_array([1, 2]);
+    /// linked_list.push_front(0);
+    ///
+    /// assert_eq!(linked_list.front(), Some(&0));
+    /// assert_eq!(linked_list.pop_back().as_deref(), Some(&2));
+    /// ```
+    pub fn push_front(&mut self, element: T)
+    where
+        T: Sized,
+    {
+        self.push_front_shared(F::new(element));
+    }
+
+    pub fn push_front_shared(&mut self, element: F::Pointer<T>) {
+        match self.is_empty() {
+            true => self.link_first(element),
+            false => self.link_before(self.head_index, element),
+        };
+    }
+
+    /// Same as [`append`](List::append), for symmetry with [`push_front`](List::push_front).
+    pub fn push_back(&mut self, element: T)
+    where
+        T: Sized,
+    {
+        self.append(element);
+    }
+
+    pub fn push_back_shared(&mut self, element: F::Pointer<T>) {
+        self.append_shared(element);
+    }
+
+    /// Removes the head in constant time.
+    pub fn pop_front(&mut self) -> Option<F::Pointer<T>> {
+        let removed_data = self.unlink_head();
+        self.apply_compaction_policy();
+        removed_data
+    }
+
+    /// Removes the tail in constant time.
+    pub fn pop_back(&mut self) -> Option<F::Pointer<T>> {
+        let removed_data = self.unlink_tail();
+        self.apply_compaction_policy();
+        removed_data
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        match self.is_empty() {
+            true => None,
+            false => Some(self.all_elements[self.head_index].data()),
+        }
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        match self.is_empty() {
+            true => None,
+            false => Some(self.all_elements[self.tail_index].data()),
+        }
+    }
+
+    /// Returns a mutable reference to the head if the list holds the only pointer to it.
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        match self.is_empty() {
+            true => None,
+            false => F::get_mut(self.all_elements[self.head_index].data_mut()),
+        }
+    }
+
+    /// Returns a mutable reference to the tail if the list holds the only pointer to it.
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        match self.is_empty() {
+            true => None,
+            false => F::get_mut(self.all_elements[self.tail_index].data_mut()),
+        }
+    }
+
+    /// Removes all elements. The slots stay allocated, see [`compact`](List::compact)
+    /// to release them.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Keeps the first `new_len` elements and removes the rest from the back,
+    /// has no effect if the list is not longer than `new_len`.
+    pub fn truncate(&mut self, new_len: usize) {
+        while self.len() > new_len {
+            self.unlink_tail();
+        }
+        self.apply_compaction_policy();
+    }
+
+    pub(super) fn unlink_tail(&mut self) -> Option<F::Pointer<T>> {
+        match self.is_empty() {
+            true => None,
+            false => Some(self.unlink(self.tail_index)),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use std::error::Error;
+use std::fmt;
+
+/// Error returned by the fallible operations of a [`List`](super::List).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ListError {
+    /// The index is not smaller than the length of the list, or for insertions
+    /// greater than it.
+    OutOfRange { index: usize, len: usize },
+    /// The operation needs at least one element.
+    EmptyList,
+    /// The arena can't grow to hold the requested number of elements.
+    CapacityOverflow,
+}
+
+impl fmt::Display for ListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ListError::OutOfRange { index, len } => {
+                write!(f, "index {index} out of range for list of length {len}")
+            }
+            ListError::EmptyList => f.write_str("list is empty"),
+            ListError::CapacityOverflow => f.write_str("list capacity overflow"),
+        }
+    }
+}
+
+impl Error for ListError {}
+
+/// Describes the first broken invariant [`List::validate`](super::List::validate)
+/// found in the arena of a list. Slots are positions in the arena, positions
+/// are indices in list order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ListCorruption {
+    /// The free list names a slot outside of the arena.
+    FreeSlotOutOfBounds {
+        slot_index: usize,
+        arena_size: usize,
+    },
+    /// The free list names a slot that holds an element.
+    FreeSlotOccupied { slot_index: usize },
+    /// The free list names the same slot twice.
+    DuplicateFreeSlot { slot_index: usize },
+    /// Head or tail point outside of the arena.
+    EndOutOfBounds {
+        head_index: usize,
+        tail_index: usize,
+        arena_size: usize,
+    },
+    /// The link to the element at `position` points outside of the arena.
+    LinkOutOfBounds { position: usize, slot_index: usize },
+    /// The element at `position` would live in a vacant slot.
+    VacantSlotLinked { position: usize, slot_index: usize },
+    /// The chain visits a slot a second time.
+    Cycle { position: usize, slot_index: usize },
+    /// The prev link of a slot doesn't point back to its predecessor, for the
+    /// head it has to point to the head itself.
+    BrokenBackLink {
+        slot_index: usize,
+        expected_prev: usize,
+        found_prev: usize,
+    },
+    /// The tail is reached after `counted` elements, but the arena holds `len`.
+    LengthMismatch { counted: usize, len: usize },
+    /// After `len` elements the chain has not arrived at the tail.
+    TailNotReached {
+        slot_index: usize,
+        tail_index: usize,
+    },
+    /// The next link of the tail doesn't point to the tail itself.
+    TailNotTerminated {
+        tail_index: usize,
+        next_index: usize,
+    },
+}
+
+impl fmt::Display for ListCorruption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ListCorruption::FreeSlotOutOfBounds {
+                slot_index,
+                arena_size,
+            } => write!(
+                f,
+                "free slot {slot_index} outside of arena of size {arena_size}"
+            ),
+            ListCorruption::FreeSlotOccupied { slot_index } => {
+                write!(f, "free slot {slot_index} is occupied")
+            }
+            ListCorruption::DuplicateFreeSlot { slot_index } => {
+                write!(f, "slot {slot_index} is free more than once")
+            }
+            ListCorruption::EndOutOfBounds {
+                head_index,
+                tail_index,
+                arena_size,
+            } => write!(
+                f,
+                "head {head_index} or tail {tail_index} outside of arena of size {arena_size}"
+            ),
+            ListCorruption::LinkOutOfBounds {
+                position,
+                slot_index,
+            } => write!(
+                f,
+                "element {position} linked to slot {slot_index} outside of the arena"
+            ),
+            ListCorruption::VacantSlotLinked {
+                position,
+                slot_index,
+            } => write!(f, "element {position} linked to vacant slot {slot_index}"),
+            ListCorruption::Cycle {
+                position,
+                slot_index,
+            } => write!(f, "element {position} revisits slot {slot_index}"),
+            ListCorruption::BrokenBackLink {
+                slot_index,
+                expected_prev,
+                found_prev,
+            } => write!(
+                f,
+                "slot {slot_index} links back to {found_prev} instead of {expected_prev}"
+            ),
+            ListCorruption::LengthMismatch { counted, len } => {
+                write!(f, "tail reached after {counted} of {len} elements")
+            }
+            ListCorruption::TailNotReached {
+                slot_index,
+                tail_index,
+            } => write!(
+                f,
+                "chain ends in slot {slot_index} instead of tail {tail_index}"
+            ),
+            ListCorruption::TailNotTerminated {
+                tail_index,
+                next_index,
+            } => write!(
+                f,
+                "tail {tail_index} links to {next_index} instead of itself"
+            ),
+        }
+    }
+}
+
+impl Error for ListCorruption {}
@@ -0,0 +1,159 @@
+use super::{ExtractIf, List};
+use crate::pointer_family::{BoxFamily, PointerFamily, SharedPointerFamily};
+
+impl<T: ?Sized, F: PointerFamily> List<T, F> {
+    /// Keeps only the elements the predicate returns `true` for, in a single
+    /// pass over the list.
+    ///
+    /// ```
+    /// use container::List;
+    ///
+    /// let mut linked_list = List::from_array([1, 2, 3, 4]);
+    /// linked_list.retain(|element| element % 2 == 0);
+    ///
+    /// assert_eq!(linked_list, List::from_array([2, 4]));
+    /// ```
+    pub fn retain<P>(&mut self, mut predicate: P)
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.extract_if(|element| !predicate(element))
+            .for_each(drop);
+        self.apply_compaction_policy();
+    }
+
+    /// Keeps only the slots the predicate returns `true` for, handing it the
+    /// pointer of every element in list order.
+    fn retain_pointers<P>(&mut self, mut predicate: P)
+    where
+        P: FnMut(&mut F::Pointer<T>) -> bool,
+    {
+        let mut slot_index = self.head_index;
+        for _ in 0..self.len() {
+            let next_index = self.all_elements[slot_index].next_index;
+            if !predicate(self.all_elements[slot_index].data_mut()) {
+                self.unlink(slot_index);
+            }
+            slot_index = next_index;
+        }
+        self.apply_compaction_policy();
+    }
+
+    /// Removes consecutive repeated elements.
+    ///
+    /// ```
+    /// use container::List;
+    ///
+    /// let mut linked_list = List::from_array([1, 1, 2, 1, 1]);
+    /// linked_list.dedup();
+    ///
+    /// assert_eq!(linked_list, List::from_array([1, 2, 1]));
+    /// ```
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|element, kept_element| element == kept_element);
+    }
+
+    pub fn dedup_by_key<K, G>(&mut self, mut key_fn: G)
+    where
+        K: PartialEq,
+        G: FnMut(&T) -> K,
+    {
+        self.dedup_by(|element, kept_element| key_fn(element) == key_fn(kept_element));
+    }
+
+    /// Removes consecutive elements for which `same_bucket(element, kept_element)`
+    /// returns `true`, where `kept_element` is the last element kept in front of it.
+    pub fn dedup_by<G>(&mut self, mut same_bucket: G)
+    where
+        G: FnMut(&T, &T) -> bool,
+    {
+        if self.len() < 2 {
+            return;
+        }
+
+        let mut kept_index = self.head_index;
+        let mut slot_index = self.all_elements[kept_index].next_index;
+        for _ in 1..self.len() {
+            let next_index = self.all_elements[slot_index].next_index;
+            if same_bucket(
+                self.all_elements[slot_index].data(),
+                self.all_elements[kept_index].data(),
+            ) {
+                self.unlink(slot_index);
+            } else {
+                kept_index = slot_index;
+            }
+            slot_index = next_index;
+        }
+        self.apply_compaction_policy();
+    }
+
+    /// Returns a lazy iterator removing and yielding the elements the predicate
+    /// returns `true` for, in list order.
+    ///
+    /// ```
+    /// use container::List;
+    ///
+    /// let mut linked_list = List::from_array([1, 2, 3, 4, 5]);
+    /// let mut extracted = linked_list.extract_if(|element| element % 2 == 1);
+    /// assert_eq!(extracted.next().as_deref(), Some(&1));
+    /// assert_eq!(extracted.next().as_deref(), Some(&3));
+    /// drop(extracted);
+    ///
+    /// assert_eq!(linked_list, List::from_array([2, 4, 5]));
+    /// ```
+    pub fn extract_if<P>(&mut self, predicate: P) -> ExtractIf<'_, T, P, F>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        ExtractIf::new(self, predicate)
+    }
+}
+
+impl<T: Clone, F: SharedPointerFamily> List<T, F> {
+    /// Like [`retain`](List::retain), but the predicate may modify the
+    /// elements.
+    ///
+    /// Elements the list holds the only pointer to are handed out as they are.
+    /// An element shared with another pointer is cloned first, also if the
+    /// predicate neither modifies nor removes it, so afterwards the list holds
+    /// the only pointer to every kept element. Use
+    /// [`extract_if`](List::extract_if) or [`retain`](List::retain) to keep
+    /// shared elements shared.
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use container::List;
+    ///
+    /// let shared_element = Rc::new(3);
+    /// let mut linked_list = List::from_array([1, 2]);
+    /// linked_list.append_shared(Rc::clone(&shared_element));
+    /// linked_list.retain_mut(|element| {
+    ///     *element *= 10;
+    ///     *element != 20
+    /// });
+    ///
+    /// assert_eq!(linked_list, List::from_array([10, 30]));
+    /// assert_eq!(*shared_element, 3);
+    /// ```
+    pub fn retain_mut<P>(&mut self, mut predicate: P)
+    where
+        P: FnMut(&mut T) -> bool,
+    {
+        self.retain_pointers(|pointer| predicate(F::make_mut(pointer)));
+    }
+}
+
+impl<T: ?Sized> List<T, BoxFamily> {
+    /// Like [`retain`](List::retain), but the predicate may modify the
+    /// elements. Boxed elements are never shared, none of them is cloned.
+    pub fn retain_mut<P>(&mut self, mut predicate: P)
+    where
+        P: FnMut(&mut T) -> bool,
+    {
+        self.retain_pointers(|boxed_element| predicate(boxed_element));
+    }
+}
@@ -0,0 +1,56 @@
+use super::List;
+use crate::pointer_family::{PointerFamily, RcFamily};
+
+/// Lazy iterator created by [`List::extract_if`], removing and yielding every
+/// element matching the predicate.
+///
+/// Elements the iterator did not reach yet stay in the list when it is dropped.
+pub struct ExtractIf<'a, T: ?Sized, P, F: PointerFamily = RcFamily>
+where
+    P: FnMut(&T) -> bool,
+{
+    next_index: usize,
+    remaining: usize,
+    predicate: P,
+    referenced_entry: &'a mut List<T, F>,
+}
+
+impl<'a, T: ?Sized, P, F: PointerFamily> ExtractIf<'a, T, P, F>
+where
+    P: FnMut(&T) -> bool,
+{
+    pub fn new(ref_list: &'a mut List<T, F>, predicate: P) -> ExtractIf<'a, T, P, F> {
+        ExtractIf {
+            next_index: ref_list.head_index,
+            remaining: ref_list.len(),
+            predicate,
+            referenced_entry: ref_list,
+        }
+    }
+}
+
+impl<'a, T: ?Sized, P, F: PointerFamily> Iterator for ExtractIf<'a, T, P, F>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Item = F::Pointer<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.remaining > 0 {
+            let found_index = self.next_index;
+            let found_entry = &self.referenced_entry.all_elements[found_index];
+            self.next_index = found_entry.next_index;
+            self.remaining -= 1;
+
+            if (self.predicate)(found_entry.data()) {
+                return Some(self.referenced_entry.unlink(found_index));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+}
@@ -0,0 +1,125 @@
+use std::iter::FusedIterator;
+
+use super::List;
+use crate::pointer_family::{BoxFamily, PointerFamily, RcFamily, SharedPointerFamily};
+
+/// Consuming iterator over the elements of a [`List`], created by
+/// [`List::into_iter_cloned`].
+///
+/// Elements the list holds the only pointer to are moved out, shared elements
+/// are cloned. Boxed elements are never shared, so an
+/// [`OwnedList`](super::OwnedList) moves out every element without `T: Clone`,
+/// its [`into_iter`](IntoIterator::into_iter) returns this iterator.
+pub struct IntoIter<T, F: PointerFamily = RcFamily> {
+    referenced_entry: List<T, F>,
+}
+
+impl<T, F: PointerFamily> IntoIter<T, F> {
+    pub fn new(ref_list: List<T, F>) -> IntoIter<T, F> {
+        IntoIter {
+            referenced_entry: ref_list,
+        }
+    }
+}
+
+impl<T: Clone, F: SharedPointerFamily> Iterator for IntoIter<T, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.referenced_entry.unlink_head().map(F::unwrap_or_clone)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.referenced_entry.len();
+        (remaining, Some(remaining))
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<T: Clone, F: SharedPointerFamily> DoubleEndedIterator for IntoIter<T, F> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.referenced_entry.unlink_tail().map(F::unwrap_or_clone)
+    }
+}
+
+impl<T: Clone, F: SharedPointerFamily> ExactSizeIterator for IntoIter<T, F> {}
+
+impl<T: Clone, F: SharedPointerFamily> FusedIterator for IntoIter<T, F> {}
+
+impl<T> Iterator for IntoIter<T, BoxFamily> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.referenced_entry
+            .unlink_head()
+            .map(|boxed_element| *boxed_element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.referenced_entry.len();
+        (remaining, Some(remaining))
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T, BoxFamily> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.referenced_entry
+            .unlink_tail()
+            .map(|boxed_element| *boxed_element)
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T, BoxFamily> {}
+
+impl<T> FusedIterator for IntoIter<T, BoxFamily> {}
+
+/// Consuming iterator created by [`List::try_into_iter`] and by
+/// [`into_iter`](IntoIterator::into_iter) of lists with shared elements.
+///
+/// Elements the list holds the only pointer to are yielded as `Ok(T)`, shared
+/// elements as `Err` with the pointer, so the caller decides how to handle them.
+pub struct TryIntoIter<T, F: PointerFamily = RcFamily> {
+    referenced_entry: List<T, F>,
+}
+
+impl<T, F: PointerFamily> TryIntoIter<T, F> {
+    pub fn new(ref_list: List<T, F>) -> TryIntoIter<T, F> {
+        TryIntoIter {
+            referenced_entry: ref_list,
+        }
+    }
+}
+
+impl<T, F: PointerFamily> Iterator for TryIntoIter<T, F> {
+    type Item = Result<T, F::Pointer<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.referenced_entry.unlink_head().map(F::try_unwrap)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.referenced_entry.len();
+        (remaining, Some(remaining))
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<T, F: PointerFamily> DoubleEndedIterator for TryIntoIter<T, F> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.referenced_entry.unlink_tail().map(F::try_unwrap)
+    }
+}
+
+impl<T, F: PointerFamily> ExactSizeIterator for TryIntoIter<T, F> {}
+
+impl<T, F: PointerFamily> FusedIterator for TryIntoIter<T, F> {}
@@ -0,0 +1,68 @@
+use std::iter::FusedIterator;
+
+use super::List;
+use crate::pointer_family::{PointerFamily, RcFamily};
+
+/// Borrowing iterator over the elements of a [`List`], created by [`List::iter_ref`].
+///
+/// In contrast to [`ListPos`](super::ListPos) the elements are yielded as plain
+/// references, so iterating does not touch any reference count.
+pub struct Iter<'a, T: ?Sized, F: PointerFamily = RcFamily> {
+    next_index: usize,
+    back_index: usize,
+    remaining: usize,
+    referenced_entry: &'a List<T, F>,
+}
+
+impl<'a, T: ?Sized, F: PointerFamily> Iter<'a, T, F> {
+    pub fn new(ref_list: &'a List<T, F>) -> Iter<'a, T, F> {
+        Iter {
+            next_index: ref_list.head_index,
+            back_index: ref_list.tail_index,
+            remaining: ref_list.len(),
+            referenced_entry: ref_list,
+        }
+    }
+}
+
+impl<'a, T: ?Sized, F: PointerFamily> Iterator for Iter<'a, T, F> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let found_entry = &self.referenced_entry.all_elements[self.next_index];
+        self.next_index = found_entry.next_index;
+        self.remaining -= 1;
+
+        Some(&**found_entry.data())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, T: ?Sized, F: PointerFamily> DoubleEndedIterator for Iter<'a, T, F> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let found_entry = &self.referenced_entry.all_elements[self.back_index];
+        self.back_index = found_entry.prev_index;
+        self.remaining -= 1;
+
+        Some(&**found_entry.data())
+    }
+}
+
+impl<'a, T: ?Sized, F: PointerFamily> ExactSizeIterator for Iter<'a, T, F> {}
+
+impl<'a, T: ?Sized, F: PointerFamily> FusedIterator for Iter<'a, T, F> {}
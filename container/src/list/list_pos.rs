@@ -1,63 +1,109 @@
-use std::rc::Rc;
-
 use super::List;
+use crate::pointer_family::{PointerFamily, RcFamily, SharedPointerFamily};
 
-pub struct ListPos<'a, T> {
+pub struct ListPos<'a, T: ?Sized, F: PointerFamily = RcFamily> {
     position_index: Option<usize>,
-    referenced_entry: &'a List<T>,
+    // last element yielded by next_back, None while the back end is untouched
+    back_index: Option<usize>,
+    remaining: usize,
+    referenced_entry: &'a List<T, F>,
 }
 
-impl<'a, T> ListPos<'a, T> {
-    pub fn new(ref_list: &'a List<T>) -> ListPos<'a, T> {
+impl<'a, T: ?Sized, F: PointerFamily> ListPos<'a, T, F> {
+    pub fn new(ref_list: &'a List<T, F>) -> ListPos<'a, T, F> {
         ListPos {
             position_index: None,
+            back_index: None,
+            remaining: ref_list.len(),
             referenced_entry: ref_list,
         }
     }
 
-    pub fn start_at(position: usize, ref_list: &'a List<T>) -> Option<ListPos<'a, T>> {
-        let mut pos_index = ref_list.head_index;
+    pub fn start_at(position: usize, ref_list: &'a List<T, F>) -> Option<ListPos<'a, T, F>> {
+        ref_list.slot_at(position).map(|pos_index| ListPos {
+            position_index: Some(pos_index),
+            back_index: None,
+            remaining: ref_list.len() - position - 1,
+            referenced_entry: ref_list,
+        })
+    }
 
-        if position >= ref_list.len(){
+    /// Moves the position one element towards the head and returns the element there.
+    ///
+    /// Moving back from the head leaves the position in front of the list, so a
+    /// following [`next`](Iterator::next) yields the head again.
+    pub fn prev(&mut self) -> Option<F::Pointer<T>>
+    where
+        F: SharedPointerFamily,
+    {
+        let valid_pos_index = self.position_index?;
+        let cur_prev_index = self.referenced_entry.all_elements[valid_pos_index].prev_index;
+        // the element moved away from is yielded again by the following next
+        self.remaining += 1;
+        if valid_pos_index == self.referenced_entry.head_index {
+            self.position_index = None;
             return None;
         }
 
-        for _ in 0..position{
-            pos_index = ref_list.all_elements[pos_index].next_index;
-        }
-
-        Some(ListPos {
-            position_index: Some(pos_index),
-            referenced_entry: ref_list,
-        })
+        self.position_index.replace(cur_prev_index);
+        Some(F::clone_pointer(
+            self.referenced_entry.all_elements[cur_prev_index].data(),
+        ))
     }
 }
 
-impl<'a, T> Iterator for ListPos<'a, T> {
-    type Item = Rc<T>;
+impl<'a, T: ?Sized, F: SharedPointerFamily> Iterator for ListPos<'a, T, F> {
+    type Item = F::Pointer<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let found_index: usize;
-        match self.position_index {
-            None => {
-                if self.referenced_entry.all_elements.is_empty() {
-                    return None;
-                }
-                found_index = self.referenced_entry.head_index;
-            }
-            Some(valid_pos_index) => {
-                let cur_next_index = self.referenced_entry.all_elements[valid_pos_index].next_index;
-                let tail_index = self.referenced_entry.tail_index;
-                if valid_pos_index == tail_index && cur_next_index == tail_index {
-                    return None;
-                }
-                found_index = cur_next_index;
-            }
+        if self.remaining == 0 {
+            return None;
         }
 
+        let found_index = match self.position_index {
+            None => self.referenced_entry.head_index,
+            Some(valid_pos_index) => self.referenced_entry.all_elements[valid_pos_index].next_index,
+        };
+
         self.position_index.replace(found_index);
-        let holded_data = Rc::clone(&self.referenced_entry.all_elements[found_index].hold_data);
+        self.remaining -= 1;
+        let holded_data = F::clone_pointer(self.referenced_entry.all_elements[found_index].data());
 
         Some(holded_data)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+/// Yields the elements from the tail towards the position. Both ends stop
+/// where they meet, [`prev`](ListPos::prev) hands elements back to both of them.
+impl<'a, T: ?Sized, F: SharedPointerFamily> DoubleEndedIterator for ListPos<'a, T, F> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let found_index = match self.back_index {
+            None => self.referenced_entry.tail_index,
+            Some(valid_back_index) => {
+                self.referenced_entry.all_elements[valid_back_index].prev_index
+            }
+        };
+
+        self.back_index.replace(found_index);
+        self.remaining -= 1;
+
+        Some(F::clone_pointer(
+            self.referenced_entry.all_elements[found_index].data(),
+        ))
+    }
 }
+
+// Not fused, moving the position back with prev continues the iteration.
+impl<'a, T: ?Sized, F: SharedPointerFamily> ExactSizeIterator for ListPos<'a, T, F> {}
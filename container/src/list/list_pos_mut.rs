@@ -1,98 +1,223 @@
-use std::rc::Rc;
-
 use super::List;
+use crate::pointer_family::{PointerFamily, RcFamily, SharedPointerFamily};
 
 // Implement one for Mutable and Const references
-pub struct MutListPos<'a, T> {
+pub struct MutListPos<'a, T: ?Sized, F: PointerFamily = RcFamily> {
     position_index: Option<usize>,
-    referenced_entry: &'a mut List<T>,
+    // last element yielded by next_back, None while the back end is untouched
+    back_index: Option<usize>,
+    remaining: usize,
+    referenced_entry: &'a mut List<T, F>,
 }
 
-impl<'a, T> MutListPos<'a, T> {
-    pub fn new(ref_list: &'a mut List<T>) -> MutListPos<'a, T> {
+impl<'a, T: ?Sized, F: PointerFamily> MutListPos<'a, T, F> {
+    pub fn new(ref_list: &'a mut List<T, F>) -> MutListPos<'a, T, F> {
         MutListPos {
             position_index: None,
+            back_index: None,
+            remaining: ref_list.len(),
             referenced_entry: ref_list,
         }
     }
 
-    pub fn start_at(position: usize, ref_list: &'a mut List<T>) -> Option<MutListPos<'a, T>> {
-        if position >= ref_list.len() {
-            return None;
-        }
-
-        let mut pos_index = ref_list.head_index;
-        for _ in 0..position {
-            pos_index = ref_list.all_elements[pos_index].next_index;
-        }
+    pub fn start_at(position: usize, ref_list: &'a mut List<T, F>) -> Option<MutListPos<'a, T, F>> {
+        let pos_index = ref_list.slot_at_mut(position)?;
 
         Some(MutListPos {
             position_index: Some(pos_index),
+            back_index: None,
+            remaining: ref_list.len() - position - 1,
             referenced_entry: ref_list,
         })
     }
 
-    pub fn remove(self) -> Option<Rc<T>>
+    pub fn remove(mut self) -> Option<F::Pointer<T>> {
+        self.remove_current()
+    }
+
+    /// Removes the current element in constant time.
+    ///
+    /// The position moves back to the predecessor of the removed element, so a
+    /// following [`next`](Iterator::next) yields its successor. This allows
+    /// removing elements while iterating.
+    pub fn remove_current(&mut self) -> Option<F::Pointer<T>> {
+        let current_position = self.position_index?;
+        let list_entries = &self.referenced_entry.all_elements;
+
+        self.position_index = match current_position == self.referenced_entry.head_index {
+            true => None,
+            false => Some(list_entries[current_position].prev_index),
+        };
+
+        Some(self.referenced_entry.unlink(current_position))
+    }
+
+    /// Inserts an element in front of the current element, the position stays
+    /// at the current element. Without a current element the new element
+    /// becomes the head.
+    pub fn insert_before(&mut self, element: T)
     where
-        T: Default, // we need default for T to clear the element
+        T: Sized,
     {
-        self.position_index.and_then(|current_position| {
-            let head_index = self.referenced_entry.head_index;
-            let list_entries = &mut self.referenced_entry.all_elements;
-            let free_indices = &mut self.referenced_entry.free_indices;
-
-            // if we are at the head index we just need to update the head index
-            if current_position == self.referenced_entry.head_index {
-                // we just need to update the head index
-                let next_index = list_entries[head_index].next_index;
-                self.referenced_entry.head_index = next_index;
-            } else {
-                // find previous index
-                let mut prev_index = head_index;
-
-                while list_entries[prev_index].next_index != current_position {
-                    prev_index = list_entries[prev_index].next_index;
-                }
-
-                list_entries[prev_index].next_index = list_entries[current_position].next_index;
+        self.insert_before_shared(F::new(element));
+    }
+
+    pub fn insert_before_shared(&mut self, element: F::Pointer<T>) {
+        match self.position_index {
+            Some(current_position) => {
+                self.referenced_entry.link_before(current_position, element);
             }
-            // Clear data with remove
-            let retr_data = Rc::clone(&list_entries[current_position].hold_data);
-            list_entries[current_position].hold_data = Rc::default();
+            None => self.push_head(element),
+        }
+    }
+
+    /// Inserts an element behind the current element, so it is yielded by the
+    /// following [`next`](Iterator::next). Without a current element the new
+    /// element becomes the head.
+    pub fn insert_after(&mut self, element: T)
+    where
+        T: Sized,
+    {
+        self.insert_after_shared(F::new(element));
+    }
 
-            // current entry is free, so the entry is free again
-            free_indices.push_back(current_position);
+    pub fn insert_after_shared(&mut self, element: F::Pointer<T>) {
+        match self.position_index {
+            Some(current_position) => {
+                self.referenced_entry.link_after(current_position, element);
+                self.remaining += 1;
+            }
+            None => self.push_head(element),
+        }
+    }
 
-            Some(retr_data)
-        })
+    /// Returns a mutable reference to the current element if the list holds the
+    /// only pointer to it.
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        let current_position = self.position_index?;
+        F::get_mut(self.referenced_entry.all_elements[current_position].data_mut())
     }
-}
 
-impl<'a, T> Iterator for MutListPos<'a, T> {
-    type Item = Rc<T>;
+    /// Returns a mutable reference to the current element, cloning it first if
+    /// it is shared with another pointer.
+    pub fn make_mut(&mut self) -> Option<&mut T>
+    where
+        T: Clone,
+    {
+        let current_position = self.position_index?;
+        Some(F::make_mut(
+            self.referenced_entry.all_elements[current_position].data_mut(),
+        ))
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let found_index: usize;
+    /// Moves the position one element towards the head and returns the element there.
+    ///
+    /// Moving back from the head leaves the position in front of the list, so a
+    /// following [`next`](Iterator::next) yields the head again.
+    pub fn prev(&mut self) -> Option<F::Pointer<T>>
+    where
+        F: SharedPointerFamily,
+    {
+        let valid_pos_index = self.position_index?;
+        let cur_prev_index = self.referenced_entry.all_elements[valid_pos_index].prev_index;
+        // the element moved away from is yielded again by the following next
+        self.remaining += 1;
+        if valid_pos_index == self.referenced_entry.head_index {
+            self.position_index = None;
+            return None;
+        }
+
+        self.position_index.replace(cur_prev_index);
+        Some(F::clone_pointer(
+            self.referenced_entry.all_elements[cur_prev_index].data(),
+        ))
+    }
+
+    /// Splits the list behind the current element and returns the elements
+    /// following it as a new list. Without a current element the whole list is
+    /// split off. Like [`List::split_off`] the shorter part is moved, the
+    /// handles stay valid for the longer part.
+    pub fn split_at_cursor(&mut self) -> List<T, F> {
+        let list = &mut *self.referenced_entry;
+        self.remaining = 0;
+        self.back_index = None;
         match self.position_index {
-            None => {
-                if self.referenced_entry.all_elements.is_empty() {
-                    return None;
-                }
-                found_index = self.referenced_entry.head_index;
+            None => list.split_off(0),
+            Some(current_position) if current_position == list.tail_index => List::default(),
+            Some(current_position) => {
+                let first_index = list.all_elements[current_position].next_index;
+                let split_list = list.split_off_slot(first_index);
+                // the current element may have moved, it is the tail now
+                self.position_index = Some(list.tail_index);
+                split_list
             }
-            Some(valid_pos_index) => {
-                let cur_next_index = self.referenced_entry.all_elements[valid_pos_index].next_index;
-                let tail_index = self.referenced_entry.tail_index;
-                if valid_pos_index == tail_index && cur_next_index == tail_index {
-                    return None;
-                }
-                found_index = cur_next_index;
+        }
+    }
+
+    fn push_head(&mut self, element: F::Pointer<T>) {
+        self.remaining += 1;
+        match self.referenced_entry.is_empty() {
+            true => self.referenced_entry.link_first(element),
+            false => {
+                let head_index = self.referenced_entry.head_index;
+                self.referenced_entry.link_before(head_index, element)
             }
+        };
+    }
+}
+
+impl<'a, T: ?Sized, F: SharedPointerFamily> Iterator for MutListPos<'a, T, F> {
+    type Item = F::Pointer<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
         }
 
+        let found_index = match self.position_index {
+            None => self.referenced_entry.head_index,
+            Some(valid_pos_index) => self.referenced_entry.all_elements[valid_pos_index].next_index,
+        };
+
         self.position_index.replace(found_index);
-        let holded_data = Rc::clone(&self.referenced_entry.all_elements[found_index].hold_data);
+        self.remaining -= 1;
+        let holded_data = F::clone_pointer(self.referenced_entry.all_elements[found_index].data());
 
         Some(holded_data)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+/// Yields the elements from the tail towards the position. Both ends stop
+/// where they meet, elements inserted behind the position are yielded by either end.
+impl<'a, T: ?Sized, F: SharedPointerFamily> DoubleEndedIterator for MutListPos<'a, T, F> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let found_index = match self.back_index {
+            None => self.referenced_entry.tail_index,
+            Some(valid_back_index) => {
+                self.referenced_entry.all_elements[valid_back_index].prev_index
+            }
+        };
+
+        self.back_index.replace(found_index);
+        self.remaining -= 1;
+
+        Some(F::clone_pointer(
+            self.referenced_entry.all_elements[found_index].data(),
+        ))
+    }
 }
+
+// Not fused, moving the position back or inserting behind it continues the iteration.
+impl<'a, T: ?Sized, F: SharedPointerFamily> ExactSizeIterator for MutListPos<'a, T, F> {}
@@ -0,0 +1,123 @@
+use std::iter::FusedIterator;
+
+use super::{List, SlotData};
+use crate::pointer_family::{BoxFamily, PointerFamily, RcFamily, SharedPointerFamily};
+
+/// Mutable iterator over the elements of a [`List`], created by [`List::values_mut`].
+///
+/// Elements are handed out with copy-on-write semantics: an element that is
+/// shared with other pointers is cloned first, so modifications never leak into
+/// other owners of the element.
+///
+/// Creating the iterator splits the arena into a reference per slot, which
+/// takes time linear in the number of slots. The elements are then taken out
+/// one by one in list order.
+pub struct ValuesMut<'a, T, F: PointerFamily = RcFamily> {
+    next_index: usize,
+    back_index: usize,
+    remaining: usize,
+    // next and prev index of every slot
+    slot_links: Vec<(usize, usize)>,
+    // the element of every occupied slot until it is handed out
+    slot_elements: Vec<Option<&'a mut F::Pointer<T>>>,
+}
+
+impl<'a, T, F: PointerFamily> ValuesMut<'a, T, F> {
+    pub fn new(ref_list: &'a mut List<T, F>) -> ValuesMut<'a, T, F> {
+        let remaining = ref_list.len();
+        let (slot_links, slot_elements) = ref_list
+            .all_elements
+            .iter_mut()
+            .map(|entry| {
+                let slot_element = match &mut entry.hold_data {
+                    SlotData::Occupied(hold_data) => Some(hold_data),
+                    SlotData::Vacant => None,
+                };
+                ((entry.next_index, entry.prev_index), slot_element)
+            })
+            .unzip();
+
+        ValuesMut {
+            next_index: ref_list.head_index,
+            back_index: ref_list.tail_index,
+            remaining,
+            slot_links,
+            slot_elements,
+        }
+    }
+
+    fn take_next(&mut self) -> Option<&'a mut F::Pointer<T>> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let found_index = self.next_index;
+        self.remaining -= 1;
+        self.next_index = self.slot_links[found_index].0;
+        self.slot_elements[found_index].take()
+    }
+
+    fn take_back(&mut self) -> Option<&'a mut F::Pointer<T>> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let found_index = self.back_index;
+        self.remaining -= 1;
+        self.back_index = self.slot_links[found_index].1;
+        self.slot_elements[found_index].take()
+    }
+}
+
+impl<'a, T: Clone + 'a, F: SharedPointerFamily> Iterator for ValuesMut<'a, T, F> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.take_next().map(F::make_mut)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, T: Clone + 'a, F: SharedPointerFamily> DoubleEndedIterator for ValuesMut<'a, T, F> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.take_back().map(F::make_mut)
+    }
+}
+
+impl<'a, T: Clone + 'a, F: SharedPointerFamily> ExactSizeIterator for ValuesMut<'a, T, F> {}
+
+impl<'a, T: Clone + 'a, F: SharedPointerFamily> FusedIterator for ValuesMut<'a, T, F> {}
+
+// boxed elements are never shared, they are handed out without cloning
+impl<'a, T: 'a> Iterator for ValuesMut<'a, T, BoxFamily> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.take_next().map(|boxed_element| &mut **boxed_element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, T: 'a> DoubleEndedIterator for ValuesMut<'a, T, BoxFamily> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.take_back().map(|boxed_element| &mut **boxed_element)
+    }
+}
+
+impl<'a, T: 'a> ExactSizeIterator for ValuesMut<'a, T, BoxFamily> {}
+
+impl<'a, T: 'a> FusedIterator for ValuesMut<'a, T, BoxFamily> {}
@@ -1,19 +1,68 @@
-use std::cmp;
 use std::collections::VecDeque;
-use std::ops::Index;
-use std::rc::Rc;
-use std::usize;
+use std::ops::{Index, IndexMut};
+
+use crate::pointer_family::{ArcFamily, BoxFamily, PointerFamily, RcFamily, SharedPointerFamily};
+
+pub(crate) mod arena;
+
+mod capacity;
+
+mod checked;
+
+mod compaction;
+pub use self::compaction::{CompactionPolicy, SlotRemap};
+
+mod deque;
+
+mod error;
+pub use self::error::{ListCorruption, ListError};
+
+mod filtering;
+
+mod list_extract_if;
+pub use self::list_extract_if::ExtractIf;
+
+mod list_into_iter;
+pub use self::list_into_iter::{IntoIter, TryIntoIter};
+
+mod list_iter;
+pub use self::list_iter::Iter;
 
 mod list_pos;
-use self::list_pos::ListPos;
+pub use self::list_pos::ListPos;
 
 mod list_pos_mut;
-use self::list_pos_mut::MutListPos;
+pub use self::list_pos_mut::MutListPos;
+
+mod list_values_mut;
+pub use self::list_values_mut::ValuesMut;
+
+mod node_handle;
+pub use self::node_handle::NodeHandle;
+
+mod position_cache;
+use self::position_cache::PositionCache;
+
+#[cfg(feature = "serde")]
+pub mod serde_shared;
+
+#[cfg(feature = "serde")]
+mod serialization;
+
+mod snapshot;
+
+mod sorting;
+
+mod splicing;
+
+mod traits;
+
+mod validation;
 
 #[cfg(test)]
 mod tests;
 
-/// A single linked list that can be used for dynamic insertion/removal
+/// A doubly linked list that can be used for dynamic insertion/removal
 ///
 /// The "default" usage of this type as a list is to use [`append`] to add to
 /// the list. Iterating over `List` goes front to back.
@@ -51,193 +100,697 @@ mod tests;
 /// ```
 /// This is more aligned to the behavior of a linked list.
 ///
+/// The pointer type is selected by the [`PointerFamily`] parameter. Use [`SyncList`] to move or share a list
+/// between threads, its _shared methods take an [`Arc`](std::sync::Arc). [`OwnedList`] stores the elements
+/// in a [`Box`] without any reference counting:
+/// ```
+/// use std::sync::Arc;
+/// use std::thread;
+/// use container::list::SyncList;
+///
+/// let mut linked_list = SyncList::from([1, 2]);
+/// linked_list.append_shared(Arc::new(3));
+///
+/// let sum = thread::spawn(move || linked_list.iter_ref().sum::<i32>()).join().unwrap();
+/// assert_eq!(sum, 6);
+/// ```
+///
+/// Logical indices shift whenever elements are inserted or removed in front of them. Methods with the suffix
+/// _with_handle return a [`NodeHandle`] instead, which keeps referring to the same node until it is removed:
+/// ```
+/// use container::List;
+///
+/// let mut linked_list = List::from_array([0, 1]);
+/// let handle = linked_list.append_with_handle(2);
+/// linked_list.insert_before(0, -1);
+///
+/// assert_eq!(linked_list.get_by_handle(handle), Some(&2));
+/// linked_list.remove_by_handle(handle);
+/// assert_eq!(linked_list.get_by_handle(handle), None);
+/// ```
+///
+/// Every entry knows its predecessor as well as its successor, so a cursor obtained with [`pos_mut`] can
+/// remove the current element, insert around it and move backwards in constant time:
+/// ```
+/// use container::List;
+///
+/// let mut linked_list = List::from_array([0, 1, 2, 3]);
+/// let mut cursor = linked_list.iter_mut();
+/// while let Some(element) = cursor.next() {
+///     if *element % 2 == 1 {
+///         cursor.remove_current();
+///     }
+/// }
+///
+/// assert_eq!(linked_list.len(), 2);
+/// assert_eq!(linked_list[1], 2);
+/// ```
+///
 /// [`append`]: List::append
 /// [`append_shared`]: List::append_shared
 /// [`insert_before`]: List::insert_before
-pub struct List<T> {
+/// [`pos_mut`]: List::pos_mut
+pub struct List<T: ?Sized, F: PointerFamily = RcFamily> {
     head_index: usize,
     tail_index: usize,
-    all_elements: Vec<ListEntry<T>>,
+    all_elements: Vec<ListEntry<F::Pointer<T>>>,
     free_indices: VecDeque<usize>,
+    compaction_policy: CompactionPolicy,
+    position_cache: PositionCache,
+    // compactions by the policy not yet taken by take_last_remap, in one remap
+    pending_remap: Option<SlotRemap>,
+    // every generation given to a slot is taken from here, so a generation is
+    // never given twice, not even after the arena shrank
+    next_generation: usize,
 }
 
-struct ListEntry<T> {
-    hold_data: Rc<T>, // we hold a reference counted value here for convenience
-    next_index: usize,
+// The head references itself as its predecessor and the tail references
+// itself as its successor, so both ends of the list are self loops.
+#[derive(Clone)]
+pub(crate) struct ListEntry<P> {
+    pub(crate) hold_data: SlotData<P>,
+    pub(crate) next_index: usize, // only meaningful for occupied slots
+    pub(crate) prev_index: usize,
+    pub(crate) generation: usize, // renewed whenever the slot is freed, see NodeHandle
 }
 
-impl<T> List<T> {
-    pub fn new() -> List<T> {
+#[derive(Clone)]
+pub(crate) enum SlotData<P> {
+    Occupied(P), // we hold a reference counted value here for convenience
+    Vacant,
+}
+
+impl<P> ListEntry<P> {
+    pub(crate) fn is_occupied(&self) -> bool {
+        matches!(self.hold_data, SlotData::Occupied(_))
+    }
+
+    // Only slots reachable through the links are accessed, these are always occupied
+    pub(crate) fn data(&self) -> &P {
+        match &self.hold_data {
+            SlotData::Occupied(hold_data) => hold_data,
+            SlotData::Vacant => unreachable!("vacant slot linked into the list"),
+        }
+    }
+
+    pub(crate) fn data_mut(&mut self) -> &mut P {
+        match &mut self.hold_data {
+            SlotData::Occupied(hold_data) => hold_data,
+            SlotData::Vacant => unreachable!("vacant slot linked into the list"),
+        }
+    }
+}
+
+impl<T: ?Sized, F: PointerFamily> Default for List<T, F> {
+    fn default() -> Self {
         List {
             head_index: 0,
             tail_index: 0,
             all_elements: Vec::new(),
             free_indices: VecDeque::new(),
+            compaction_policy: CompactionPolicy::default(),
+            position_cache: PositionCache::default(),
+            pending_remap: None,
+            next_generation: 0,
         }
     }
+}
 
-    pub fn from_array<const COUNT: usize>(given_array: [T; COUNT]) -> List<T> {
-        match given_array.len() {
-            0 => List::new(),
-            arr_len => List {
-                head_index: 0,
-                tail_index: arr_len - 1,
-                all_elements: given_array
-                    .into_iter()
-                    .enumerate()
-                    .map(|(pos, value)| ListEntry {
-                        hold_data: Rc::new(value),
-                        next_index: cmp::min(pos + 1, arr_len - 1),
-                    })
-                    .collect(),
-                free_indices: VecDeque::new(),
-            },
-        }
+// The constructors without suffix are only defined for the Rc family, so
+// `List::new()` infers it. Inherent `new` functions for further families
+// would make every such call ambiguous, they use the _in variants instead.
+impl<T: ?Sized> List<T> {
+    pub fn new() -> List<T> {
+        List::default()
     }
 
-    pub fn append(&mut self, element: T) {
-        self.append_shared(Rc::new(element));
+    /// Creates an empty list with room for `capacity` elements.
+    pub fn with_capacity(capacity: usize) -> List<T> {
+        List::with_capacity_in(capacity)
     }
 
-    pub fn append_shared(&mut self, element: Rc<T>) {
-        if let Some(free_index) = self.free_indices.pop_front() {
-            self.all_elements[self.tail_index].next_index = free_index;
-            self.tail_index = free_index;
-            self.all_elements[free_index].hold_data = element;
-            self.all_elements[free_index].next_index = free_index;
-        } else {
-            if let Some(last_element) = self.all_elements.last_mut() {
-                self.tail_index += 1;
-                last_element.next_index = self.tail_index;
-            }
+    pub fn from_array<const COUNT: usize>(given_array: [T; COUNT]) -> List<T>
+    where
+        T: Sized,
+    {
+        List::from(given_array)
+    }
+}
+
+impl<T: ?Sized, F: PointerFamily> List<T, F> {
+    /// Like [`new`](List::new) for every pointer family.
+    ///
+    /// ```
+    /// use container::list::{OwnedList, SyncList};
+    ///
+    /// let mut sync_list = SyncList::new_in();
+    /// sync_list.append(1);
+    /// let owned_list = OwnedList::from_array_in([1]);
+    ///
+    /// assert!(sync_list.iter_ref().eq(owned_list.iter_ref()));
+    /// ```
+    pub fn new_in() -> List<T, F> {
+        List::default()
+    }
 
-            // tail index references itself
-            self.all_elements.push(ListEntry {
-                hold_data: element,
-                next_index: self.tail_index,
-            });
+    /// Like [`with_capacity`](List::with_capacity) for every pointer family.
+    pub fn with_capacity_in(capacity: usize) -> List<T, F> {
+        List {
+            all_elements: Vec::with_capacity(capacity),
+            ..List::default()
         }
     }
 
-    pub fn head(&self) -> Option<Rc<T>> {
+    /// Like [`from_array`](List::from_array) for every pointer family.
+    pub fn from_array_in<const COUNT: usize>(given_array: [T; COUNT]) -> List<T, F>
+    where
+        T: Sized,
+    {
+        List::from(given_array)
+    }
+
+    pub fn append(&mut self, element: T)
+    where
+        T: Sized,
+    {
+        self.append_shared(F::new(element));
+    }
+
+    pub fn append_shared(&mut self, element: F::Pointer<T>) {
+        self.append_shared_with_handle(element);
+    }
+
+    pub fn head(&self) -> Option<F::Pointer<T>>
+    where
+        F: SharedPointerFamily,
+    {
         match self.len() {
             0 => None,
-            _ => Some(Rc::clone(&self.all_elements[self.head_index].hold_data)),
+            _ => Some(F::clone_pointer(self.all_elements[self.head_index].data())),
         }
     }
 
-    pub fn head_iter(&self) -> Option<ListPos<'_, T>> {
+    pub fn head_iter(&self) -> Option<ListPos<'_, T, F>> {
         ListPos::start_at(0, self)
     }
 
-    pub fn head_iter_mut(&mut self) -> Option<MutListPos<'_, T>> {
+    pub fn head_iter_mut(&mut self) -> Option<MutListPos<'_, T, F>> {
         MutListPos::start_at(0, self)
     }
 
-    pub fn iter(&self) -> ListPos<'_, T> {
+    pub fn iter(&self) -> ListPos<'_, T, F> {
+        ListPos::new(self)
+    }
+
+    /// Iterates over references to the elements without cloning any element pointer.
+    ///
+    /// ```
+    /// use container::List;
+    ///
+    /// let linked_list = List::from_array([1, 2, 3]);
+    /// let sum: i32 = linked_list.iter_ref().sum();
+    ///
+    /// assert_eq!(sum, 6);
+    /// ```
+    pub fn iter_ref(&self) -> Iter<'_, T, F> {
+        Iter::new(self)
+    }
+
+    /// Iterates over shared pointers to the elements, same as [`iter`](List::iter).
+    pub fn iter_rc(&self) -> ListPos<'_, T, F> {
         ListPos::new(self)
     }
 
-    pub fn iter_mut(&mut self) -> MutListPos<'_, T> {
+    pub fn iter_mut(&mut self) -> MutListPos<'_, T, F> {
         MutListPos::new(self)
     }
 
-    pub fn pos(&self, pos: usize) -> Option<ListPos<'_, T>> {
+    pub fn pos(&self, pos: usize) -> Option<ListPos<'_, T, F>> {
         ListPos::start_at(pos, self)
     }
 
-    pub fn pos_mut(&mut self, pos: usize) -> Option<MutListPos<'_, T>> {
+    pub fn pos_mut(&mut self, pos: usize) -> Option<MutListPos<'_, T, F>> {
         MutListPos::start_at(pos, self)
     }
 
-    pub fn at(&self, index: usize) -> Option<Rc<T>> {
-        if index >= self.len() {
-            return None;
-        }
+    pub fn at(&self, index: usize) -> Option<F::Pointer<T>>
+    where
+        F: SharedPointerFamily,
+    {
+        self.slot_at(index)
+            .map(|slot| F::clone_pointer(self.all_elements[slot].data()))
+    }
 
-        self.iter().nth(index)
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.slot_at(index)
+            .map(|slot| &**self.all_elements[slot].data())
     }
 
-    pub fn get<'a>(&'a self, index: usize) -> Option<&'a T> {
-        if index >= self.len() {
-            return None;
-        }
+    /// Returns a mutable reference to the element if the list holds the only
+    /// pointer to it, `None` if the element is shared or out of range.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.slot_at_mut(index)
+            .and_then(|slot| F::get_mut(self.all_elements[slot].data_mut()))
+    }
+
+    /// Returns a mutable reference to the element, cloning it first if it is
+    /// shared with another pointer (copy-on-write).
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use container::List;
+    ///
+    /// let shared_element = Rc::new(1);
+    /// let mut linked_list = List::new();
+    /// linked_list.append_shared(Rc::clone(&shared_element));
+    ///
+    /// assert!(linked_list.get_mut(0).is_none());
+    /// *linked_list.make_mut(0).unwrap() = 2;
+    ///
+    /// assert_eq!(linked_list[0], 2);
+    /// assert_eq!(*shared_element, 1);
+    /// ```
+    pub fn make_mut(&mut self, index: usize) -> Option<&mut T>
+    where
+        T: Clone,
+    {
+        self.slot_at_mut(index)
+            .map(|slot| F::make_mut(self.all_elements[slot].data_mut()))
+    }
+
+    /// Inserts the element in front of the element at the index, an index equal
+    /// to the length appends it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is greater than the length, see
+    /// [`try_insert_before`](List::try_insert_before) for a fallible version.
+    pub fn insert_before(&mut self, insert_index: usize, element: T)
+    where
+        T: Sized,
+    {
+        self.insert_before_shared(insert_index, F::new(element));
+    }
 
-        let mut count_index = self.head_index;
-        for _ in 0..index {
-            count_index = self.all_elements[count_index].next_index;
+    pub fn insert_before_shared(&mut self, insert_index: usize, element: F::Pointer<T>) {
+        if let Err(error) = self.try_insert_before_shared(insert_index, element) {
+            panic!("{error}");
         }
+    }
+
+    pub fn len(&self) -> usize {
+        // free_indices is always smaller or equal then all_elements
+        self.all_elements.len() - self.free_indices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes the element at the index, `None` if the index is out of range.
+    /// [`try_remove_at`](List::try_remove_at) reports the reason instead.
+    pub fn remove_at(&mut self, index: usize) -> Option<F::Pointer<T>> {
+        self.try_remove_at(index).ok()
+    }
+
+    pub fn append_with_handle(&mut self, element: T) -> NodeHandle
+    where
+        T: Sized,
+    {
+        self.append_shared_with_handle(F::new(element))
+    }
+
+    pub fn append_shared_with_handle(&mut self, element: F::Pointer<T>) -> NodeHandle {
+        let new_index = match self.is_empty() {
+            true => self.link_first(element),
+            false => self.link_after(self.tail_index, element),
+        };
+        self.handle_of(new_index)
+    }
+
+    /// Inserts like [`insert_before`](List::insert_before) and returns a handle
+    /// to the new node, `None` if the index is greater than the length.
+    pub fn insert_before_with_handle(
+        &mut self,
+        insert_index: usize,
+        element: T,
+    ) -> Option<NodeHandle>
+    where
+        T: Sized,
+    {
+        self.insert_before_shared_with_handle(insert_index, F::new(element))
+    }
+
+    pub fn insert_before_shared_with_handle(
+        &mut self,
+        insert_index: usize,
+        element: F::Pointer<T>,
+    ) -> Option<NodeHandle> {
+        let new_index = self.try_link_at(insert_index, element).ok()?;
+        Some(self.handle_of(new_index))
+    }
+
+    /// Inserts the element directly behind the node of the handle, `None` if
+    /// the handle is stale.
+    pub fn insert_after_handle(&mut self, handle: NodeHandle, element: T) -> Option<NodeHandle>
+    where
+        T: Sized,
+    {
+        self.insert_after_handle_shared(handle, F::new(element))
+    }
+
+    pub fn insert_after_handle_shared(
+        &mut self,
+        handle: NodeHandle,
+        element: F::Pointer<T>,
+    ) -> Option<NodeHandle> {
+        let referenced_index = self.resolve_handle(handle)?;
+        let new_index = self.link_after(referenced_index, element);
+        Some(self.handle_of(new_index))
+    }
+
+    /// Inserts the element directly in front of the node of the handle, `None`
+    /// if the handle is stale.
+    pub fn insert_before_handle(&mut self, handle: NodeHandle, element: T) -> Option<NodeHandle>
+    where
+        T: Sized,
+    {
+        self.insert_before_handle_shared(handle, F::new(element))
+    }
 
-        Some(&self.all_elements[count_index].hold_data)
+    pub fn insert_before_handle_shared(
+        &mut self,
+        handle: NodeHandle,
+        element: F::Pointer<T>,
+    ) -> Option<NodeHandle> {
+        let referenced_index = self.resolve_handle(handle)?;
+        let new_index = self.link_before(referenced_index, element);
+        Some(self.handle_of(new_index))
     }
 
-    pub fn insert_before(&mut self, insert_index: usize, element: T) {
-        self.insert_before_shared(insert_index, Rc::new(element));
+    /// Returns a handle to the node currently at the logical index.
+    pub fn handle_at(&self, index: usize) -> Option<NodeHandle> {
+        self.slot_at(index).map(|slot| self.handle_of(slot))
     }
 
-    pub fn insert_before_shared(&mut self, insert_index: usize, element: Rc<T>) {
-        if insert_index >= self.len() {
-            return;
+    pub fn contains_handle(&self, handle: NodeHandle) -> bool {
+        self.resolve_handle(handle).is_some()
+    }
+
+    pub fn get_by_handle(&self, handle: NodeHandle) -> Option<&T> {
+        self.resolve_handle(handle)
+            .map(|slot| &**self.all_elements[slot].data())
+    }
+
+    pub fn get_mut_by_handle(&mut self, handle: NodeHandle) -> Option<&mut T> {
+        self.resolve_handle(handle)
+            .and_then(|slot| F::get_mut(self.all_elements[slot].data_mut()))
+    }
+
+    pub fn at_handle(&self, handle: NodeHandle) -> Option<F::Pointer<T>>
+    where
+        F: SharedPointerFamily,
+    {
+        self.resolve_handle(handle)
+            .map(|slot| F::clone_pointer(self.all_elements[slot].data()))
+    }
+
+    pub fn remove_by_handle(&mut self, handle: NodeHandle) -> Option<F::Pointer<T>> {
+        let removed_data = self.resolve_handle(handle).map(|slot| self.unlink(slot));
+        self.apply_compaction_policy();
+        removed_data
+    }
+
+    fn handle_of(&self, slot_index: usize) -> NodeHandle {
+        arena::handle_of(&self.all_elements, slot_index)
+    }
+
+    /// Returns the slot of the handle if it still refers to a node of the list.
+    fn resolve_handle(&self, handle: NodeHandle) -> Option<usize> {
+        arena::resolve_slot(&self.all_elements, handle)
+    }
+
+    /// Consumes the list and yields every element as `Ok(T)` if the list held
+    /// the only pointer to it, or as `Err` with the still shared pointer.
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use container::List;
+    ///
+    /// let shared_element = Rc::new(2);
+    /// let mut linked_list = List::from_array([1]);
+    /// linked_list.append_shared(Rc::clone(&shared_element));
+    ///
+    /// let mut element_iter = linked_list.try_into_iter();
+    /// assert_eq!(element_iter.next(), Some(Ok(1)));
+    /// assert_eq!(element_iter.next(), Some(Err(shared_element)));
+    /// ```
+    pub fn try_into_iter(self) -> TryIntoIter<T, F>
+    where
+        T: Sized,
+    {
+        TryIntoIter::new(self)
+    }
+
+    fn unlink_head(&mut self) -> Option<F::Pointer<T>> {
+        match self.is_empty() {
+            true => None,
+            false => Some(self.unlink(self.head_index)),
+        }
+    }
+
+    /// Resolves a logical index to the slot in `all_elements`, walking from
+    /// whichever end of the list is closer.
+    fn slot_at(&self, index: usize) -> Option<usize> {
+        let list_len = self.len();
+        if index >= list_len {
+            return None;
         }
-        // this element will be the new head
-        if insert_index == self.head_index {
-            if let Some(free_index) = self.free_indices.pop_front(){
-                self.all_elements[free_index].hold_data = element;
-                self.all_elements[free_index].next_index = self.head_index;
-                self.head_index = free_index;
+
+        // walk from whichever of head, tail and cached finger is closest
+        let (mut start_index, mut slot_index) = match index <= list_len / 2 {
+            true => (0, self.head_index),
+            false => (list_len - 1, self.tail_index),
+        };
+        if let Some(finger) = self.position_cache.load()
+            && finger.index.abs_diff(index) < start_index.abs_diff(index)
+        {
+            (start_index, slot_index) = (finger.index, finger.slot_index);
+        }
+
+        if start_index <= index {
+            for _ in start_index..index {
+                slot_index = self.all_elements[slot_index].next_index;
             }
-            else {
-                self.all_elements.push(ListEntry {
-                    hold_data: element,
-                    next_index: self.head_index,
-                });
-                self.head_index = self.all_elements.len() - 1;
+        } else {
+            for _ in index..start_index {
+                slot_index = self.all_elements[slot_index].prev_index;
             }
-            return;
         }
 
-        let mut actual_insertion_index = self.head_index;
-        let mut prev_insertion_index = self.head_index;
+        Some(slot_index)
+    }
 
-        // Go to the element before the referenced one
-        for _ in 0..insert_index {
-            prev_insertion_index = actual_insertion_index;
-            actual_insertion_index = self.all_elements[actual_insertion_index].next_index;
-        }
-        let referenced_index = self.all_elements[actual_insertion_index].next_index;
-        let inserted_element = ListEntry {
-            hold_data: element,
-            next_index: referenced_index,
-        };
+    /// Like [`slot_at`](List::slot_at), but moves the finger of the position
+    /// cache to the resolved slot.
+    fn slot_at_mut(&mut self, index: usize) -> Option<usize> {
+        let slot_index = self.slot_at(index)?;
+        self.position_cache.store(index, slot_index);
+        Some(slot_index)
+    }
 
-        if let Some(free_index) = self.free_indices.pop_front() {
-            self.all_elements[prev_insertion_index].next_index = free_index;
-            self.all_elements[free_index] = inserted_element;
-        }
-        else {
-            self.all_elements[prev_insertion_index].next_index = self.all_elements.len();
-            self.all_elements.push(inserted_element);
-        }
+    /// Stores the element in a free slot (or a new one) whose links point to
+    /// itself. Linking it into the list is left to the caller.
+    fn allocate_entry(&mut self, element: F::Pointer<T>) -> usize {
+        self.position_cache.invalidate();
+        arena::occupy_slot(
+            &mut self.all_elements,
+            &mut self.free_indices,
+            &mut self.next_generation,
+            element,
+        )
     }
 
-    pub fn len(&self) -> usize {
-        // free_indices is always smaller or equal then all_elements
-        self.all_elements.len() - self.free_indices.len()
+    /// A generation no slot of the list had before.
+    pub(super) fn fresh_generation(&mut self) -> usize {
+        arena::fresh_generation(&mut self.next_generation)
     }
 
-    pub fn remove_at(&mut self, index: usize) -> Option<Rc<T>>
-    where
-        T: Default,
-    {
-        self.pos_mut(index).and_then(|pos_iter| pos_iter.remove())
+    /// Inserts the element into an empty list.
+    fn link_first(&mut self, element: F::Pointer<T>) -> usize {
+        let new_index = self.allocate_entry(element);
+        self.head_index = new_index;
+        self.tail_index = new_index;
+        self.check_invariants();
+        new_index
+    }
+
+    fn link_after(&mut self, anchor_index: usize, element: F::Pointer<T>) -> usize {
+        let new_index = self.allocate_entry(element);
+        arena::link_after(
+            &mut self.all_elements,
+            &mut self.tail_index,
+            anchor_index,
+            new_index,
+        );
+        self.check_invariants();
+        new_index
+    }
+
+    fn link_before(&mut self, anchor_index: usize, element: F::Pointer<T>) -> usize {
+        let new_index = self.allocate_entry(element);
+        arena::link_before(
+            &mut self.all_elements,
+            &mut self.head_index,
+            anchor_index,
+            new_index,
+        );
+        self.check_invariants();
+        new_index
+    }
+
+    /// Detaches an occupied slot from the list and marks it as free.
+    fn unlink(&mut self, slot_index: usize) -> F::Pointer<T> {
+        arena::unlink_slot(
+            &mut self.all_elements,
+            &mut self.head_index,
+            &mut self.tail_index,
+            slot_index,
+        );
+        let removed_data = self.vacate(slot_index);
+        self.check_invariants();
+        removed_data
+    }
+
+    /// Takes the element out of a slot that is no longer linked into the list,
+    /// handles to it become stale.
+    fn vacate(&mut self, slot_index: usize) -> F::Pointer<T> {
+        self.position_cache.invalidate();
+        arena::vacate_slot(
+            &mut self.all_elements,
+            &mut self.free_indices,
+            &mut self.next_generation,
+            slot_index,
+        )
+    }
+}
+
+impl<T: Clone, F: SharedPointerFamily> List<T, F> {
+    /// Iterates over mutable references to the elements, cloning every element
+    /// that is shared with another pointer before handing it out.
+    ///
+    /// ```
+    /// use container::List;
+    ///
+    /// let mut linked_list = List::from_array([1, 2, 3]);
+    /// for element in linked_list.values_mut() {
+    ///     *element *= 10;
+    /// }
+    ///
+    /// assert_eq!(linked_list[2], 30);
+    /// ```
+    pub fn values_mut(&mut self) -> ValuesMut<'_, T, F> {
+        ValuesMut::new(self)
+    }
+
+    /// Consumes the list and yields every element, moving out the elements
+    /// the list holds the only pointer to and cloning shared ones.
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use container::List;
+    ///
+    /// let shared_element = Rc::new(2);
+    /// let mut linked_list = List::from_array([1]);
+    /// linked_list.append_shared(Rc::clone(&shared_element));
+    ///
+    /// assert_eq!(linked_list.into_iter_cloned().collect::<Vec<_>>(), [1, 2]);
+    /// assert_eq!(Rc::strong_count(&shared_element), 1);
+    /// ```
+    pub fn into_iter_cloned(self) -> IntoIter<T, F> {
+        IntoIter::new(self)
+    }
+
+    /// Consumes the list into a `Vec`, moving out uniquely held elements and
+    /// cloning shared ones.
+    pub fn into_vec(self) -> Vec<T> {
+        self.into_iter_cloned().collect()
+    }
+}
+
+impl<T> List<T, BoxFamily> {
+    /// Iterates over mutable references to the elements.
+    pub fn values_mut(&mut self) -> ValuesMut<'_, T, BoxFamily> {
+        ValuesMut::new(self)
+    }
+
+    /// Consumes the list into a `Vec`, moving out every element.
+    pub fn into_vec(self) -> Vec<T> {
+        self.into_iter().collect()
+    }
+}
+
+impl<T, F: PointerFamily, const COUNT: usize> From<[T; COUNT]> for List<T, F> {
+    fn from(given_array: [T; COUNT]) -> Self {
+        match given_array.len() {
+            0 => List::default(),
+            arr_len => List {
+                head_index: 0,
+                tail_index: arr_len - 1,
+                all_elements: given_array
+                    .into_iter()
+                    .enumerate()
+                    .map(|(pos, value)| ListEntry {
+                        hold_data: SlotData::Occupied(F::new(value)),
+                        next_index: usize::min(pos + 1, arr_len - 1),
+                        prev_index: pos.saturating_sub(1),
+                        generation: 0,
+                    })
+                    .collect(),
+                free_indices: VecDeque::new(),
+                compaction_policy: CompactionPolicy::default(),
+                position_cache: PositionCache::default(),
+                pending_remap: None,
+                next_generation: 1,
+            },
+        }
     }
 }
 
-impl<T> Index<usize> for List<T> {
+impl<T: ?Sized, F: PointerFamily> Index<usize> for List<T, F> {
     type Output = T;
 
     fn index(&self, index: usize) -> &Self::Output {
-        self.get(index)
-            .expect(format!("Index {index} out of range!").as_str())
+        self.try_get(index)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl<T: Clone, F: SharedPointerFamily> IndexMut<usize> for List<T, F> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.try_make_mut(index)
+            .unwrap_or_else(|error| panic!("{error}"))
     }
 }
+
+impl<T: ?Sized> IndexMut<usize> for List<T, BoxFamily> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        // boxed elements are never shared, get_mut only fails out of range
+        let list_len = self.len();
+        self.get_mut(index).unwrap_or_else(|| {
+            panic!(
+                "{}",
+                ListError::OutOfRange {
+                    index,
+                    len: list_len
+                }
+            )
+        })
+    }
+}
+
+/// A [`List`] storing its elements as [`Arc`](std::sync::Arc), which can be sent and shared between threads.
+pub type SyncList<T> = List<T, ArcFamily>;
+
+/// A [`List`] storing uniquely owned elements in a [`Box`].
+pub type OwnedList<T> = List<T, BoxFamily>;
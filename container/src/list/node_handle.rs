@@ -0,0 +1,21 @@
+/// Stable reference to a single node of a [`List`](super::List).
+///
+/// A handle stays valid while other elements are inserted or removed around
+/// its node. Once the node itself is removed the handle becomes stale, which
+/// the list detects by comparing the generation of the slot, even if the slot
+/// has been reused for another element in the meantime.
+///
+/// A handle is only meaningful for the list (or [`ListPool`](crate::ListPool))
+/// that created it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NodeHandle {
+    pub(crate) slot_index: usize,
+    pub(crate) generation: usize,
+}
+
+impl NodeHandle {
+    /// The slot of the node in the arena of the list.
+    pub fn slot(&self) -> usize {
+        self.slot_index
+    }
+}
@@ -0,0 +1,90 @@
+use super::List;
+use crate::pointer_family::PointerFamily;
+
+/// Remembers the last index resolved to a slot, so indexed access close to it
+/// doesn't have to walk from the head or the tail.
+///
+/// The finger is only moved by lookups through `&mut self`, so the list needs
+/// no interior mutability for it and lookups through `&self` take no lock.
+#[derive(Default)]
+pub(super) struct PositionCache {
+    // None while the cache is disabled
+    finger: Option<Option<Finger>>,
+}
+
+#[derive(Clone, Copy)]
+pub(super) struct Finger {
+    pub(super) index: usize,
+    pub(super) slot_index: usize,
+}
+
+impl PositionCache {
+    pub(super) fn is_enabled(&self) -> bool {
+        self.finger.is_some()
+    }
+
+    /// Returns an empty cache with the same setting.
+    pub(super) fn fresh(&self) -> PositionCache {
+        PositionCache {
+            finger: self.finger.map(|_| None),
+        }
+    }
+
+    /// Forgets the finger, which has to happen on every change of the links.
+    pub(super) fn invalidate(&mut self) {
+        if let Some(finger) = &mut self.finger {
+            *finger = None;
+        }
+    }
+
+    pub(super) fn load(&self) -> Option<Finger> {
+        self.finger?
+    }
+
+    pub(super) fn store(&mut self, index: usize, slot_index: usize) {
+        if let Some(finger) = &mut self.finger {
+            *finger = Some(Finger { index, slot_index });
+        }
+    }
+}
+
+// the finger describes the layout of the original, a clone starts without one
+impl Clone for PositionCache {
+    fn clone(&self) -> Self {
+        self.fresh()
+    }
+}
+
+impl<T: ?Sized, F: PointerFamily> List<T, F> {
+    /// Enables or disables the position cache.
+    ///
+    /// With the cache enabled the list remembers the last index resolved by a
+    /// mutable lookup ([`get_mut`](List::get_mut), [`make_mut`](List::make_mut),
+    /// mutable indexing, [`pos_mut`](List::pos_mut) and so on). Every lookup,
+    /// mutable or not, starts from there if that is closer than head or tail,
+    /// so sequential mutable access becomes O(1) per element. Any change to the
+    /// order of the elements clears the cache.
+    ///
+    /// ```
+    /// use container::List;
+    ///
+    /// let mut linked_list: List<usize> = (0..1000).collect();
+    /// linked_list.set_position_cache(true);
+    ///
+    /// // every lookup is a single step from the previous one
+    /// for index in 0..linked_list.len() {
+    ///     linked_list[index] *= 2;
+    /// }
+    /// assert_eq!(linked_list[999], 1998);
+    /// ```
+    pub fn set_position_cache(&mut self, enabled: bool) {
+        self.position_cache = match enabled {
+            true => PositionCache { finger: Some(None) },
+            false => PositionCache::default(),
+        };
+    }
+
+    pub fn has_position_cache(&self) -> bool {
+        self.position_cache.is_enabled()
+    }
+}
@@ -0,0 +1,90 @@
+//! Identity preserving serialization of a [`List`], for use with
+//! `#[serde(with = "container::list::serde_shared")]`.
+//!
+//! Every distinct element is written once into `values`, `order` holds the
+//! index into `values` for every position of the list. Positions that shared a
+//! pointer before serialization share one again after deserialization.
+//!
+//! ```
+//! use std::rc::Rc;
+//! use container::List;
+//! use container::list::serde_shared;
+//!
+//! let shared_element = Rc::new(String::from("shared"));
+//! let mut linked_list = List::new();
+//! linked_list.append_shared(Rc::clone(&shared_element));
+//! linked_list.append(String::from("single"));
+//! linked_list.append_shared(shared_element);
+//!
+//! let json = serde_shared::serialize(&linked_list, serde_json::value::Serializer).unwrap();
+//! assert_eq!(json["order"], serde_json::json!([0, 1, 0]));
+//!
+//! let restored: List<String> = serde_shared::deserialize(json).unwrap();
+//! assert!(Rc::ptr_eq(&restored.at(0).unwrap(), &restored.at(2).unwrap()));
+//! ```
+
+use std::collections::HashMap;
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::List;
+use crate::pointer_family::SharedPointerFamily;
+
+#[derive(Serialize)]
+struct SharedListRef<'a, T: ?Sized> {
+    values: Vec<&'a T>,
+    order: Vec<usize>,
+}
+
+#[derive(Deserialize)]
+struct SharedListOwned<T> {
+    values: Vec<T>,
+    order: Vec<usize>,
+}
+
+pub fn serialize<T, F, S>(list: &List<T, F>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: ?Sized + Serialize,
+    F: SharedPointerFamily,
+    S: Serializer,
+{
+    // the list keeps every element alive, so addresses identify them
+    let mut value_indices = HashMap::new();
+    let mut values = Vec::new();
+    let mut order = Vec::with_capacity(list.len());
+
+    for element in list.iter_ref() {
+        let address = (element as *const T).cast::<()>();
+        let value_index = *value_indices.entry(address).or_insert_with(|| {
+            values.push(element);
+            values.len() - 1
+        });
+        order.push(value_index);
+    }
+
+    SharedListRef { values, order }.serialize(serializer)
+}
+
+pub fn deserialize<'de, T, F, D>(deserializer: D) -> Result<List<T, F>, D::Error>
+where
+    T: Deserialize<'de>,
+    F: SharedPointerFamily,
+    D: Deserializer<'de>,
+{
+    let SharedListOwned { values, order } = SharedListOwned::deserialize(deserializer)?;
+    let shared_values: Vec<F::Pointer<T>> = values.into_iter().map(F::new).collect();
+
+    let mut new_list = List::default();
+    new_list.reserve(order.len());
+    for value_index in order {
+        let shared_value = shared_values.get(value_index).ok_or_else(|| {
+            D::Error::custom(format_args!(
+                "value index {value_index} out of range for {} values",
+                shared_values.len()
+            ))
+        })?;
+        new_list.append_shared(F::clone_pointer(shared_value));
+    }
+    Ok(new_list)
+}
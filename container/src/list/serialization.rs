@@ -0,0 +1,48 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{SeqAccess, Visitor};
+use serde::ser::SerializeSeq;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::List;
+use crate::pointer_family::PointerFamily;
+
+/// Serializes the elements as a plain sequence. Elements shared between
+/// several positions are written once per position, see
+/// [`serde_shared`](super::serde_shared) to keep the sharing.
+impl<T: ?Sized + Serialize, F: PointerFamily> Serialize for List<T, F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for element in self.iter_ref() {
+            seq.serialize_element(element)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>, F: PointerFamily> Deserialize<'de> for List<T, F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(ListVisitor(PhantomData))
+    }
+}
+
+struct ListVisitor<T, F>(PhantomData<(T, F)>);
+
+impl<'de, T: Deserialize<'de>, F: PointerFamily> Visitor<'de> for ListVisitor<T, F> {
+    type Value = List<T, F>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut new_list = List::default();
+        // the hint comes from the input, don't trust it with huge allocations
+        new_list.reserve(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(element) = seq.next_element()? {
+            new_list.append(element);
+        }
+        Ok(new_list)
+    }
+}
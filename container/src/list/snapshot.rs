@@ -0,0 +1,352 @@
+use std::collections::VecDeque;
+use std::io::{self, Read, Write};
+
+use super::{List, ListEntry, SlotData};
+use crate::pointer_family::PointerFamily;
+
+const SNAPSHOT_MAGIC: [u8; 4] = *b"IRXL";
+const SNAPSHOT_VERSION: u16 = 1;
+// the body holds the arena slot by slot instead of the elements in list order
+const FLAG_ARENA_LAYOUT: u16 = 1;
+
+const VACANT_SLOT: u8 = 0;
+const OCCUPIED_SLOT: u8 = 1;
+
+impl<T: ?Sized, F: PointerFamily> List<T, F> {
+    /// Writes the elements in list order into a binary snapshot, `encode`
+    /// writes the bytes of a single element.
+    ///
+    /// All numbers are little endian, indices and lengths are stored as `u64`:
+    ///
+    /// | field      | size | content                                        |
+    /// |------------|------|------------------------------------------------|
+    /// | magic      | 4    | `b"IRXL"`                                      |
+    /// | version    | 2    | currently `1`                                  |
+    /// | flags      | 2    | bit 0 set for the arena layout                 |
+    /// | length     | 8    | number of elements                             |
+    /// | arena size | 8    | number of slots, equal to the length here      |
+    /// | body       |      | per element its byte count and bytes           |
+    /// | checksum   | 8    | 64 bit FNV-1a of all bytes in front of it      |
+    ///
+    /// See [`write_arena_snapshot`](List::write_arena_snapshot) to keep the slot
+    /// numbering. The compaction policy and the position cache are settings of
+    /// the list and are not part of the snapshot.
+    ///
+    /// ```
+    /// use std::io::{Read, Write};
+    /// use container::List;
+    ///
+    /// let linked_list = List::from_array([1u32, 2, 3]);
+    /// let mut snapshot = Vec::new();
+    /// linked_list
+    ///     .write_snapshot(&mut snapshot, |element, writer| {
+    ///         writer.write_all(&element.to_le_bytes())
+    ///     })
+    ///     .unwrap();
+    ///
+    /// let restored: List<u32> = List::read_snapshot(snapshot.as_slice(), |reader| {
+    ///     let mut bytes = [0; 4];
+    ///     reader.read_exact(&mut bytes)?;
+    ///     Ok(u32::from_le_bytes(bytes))
+    /// })
+    /// .unwrap();
+    /// assert_eq!(restored, linked_list);
+    /// ```
+    pub fn write_snapshot<W, E>(&self, writer: W, encode: E) -> io::Result<()>
+    where
+        W: Write,
+        E: Fn(&T, &mut dyn Write) -> io::Result<()>,
+    {
+        let mut snapshot_writer = SnapshotWriter::new(writer);
+        snapshot_writer.write_header(0, self.len(), self.len())?;
+
+        let mut element_buffer = Vec::new();
+        for element in self.iter_ref() {
+            snapshot_writer.write_element(element, &encode, &mut element_buffer)?;
+        }
+        snapshot_writer.finish()
+    }
+
+    /// Writes a snapshot like [`write_snapshot`](List::write_snapshot), but
+    /// stores the raw arena, so the reloaded list has the same slots, free
+    /// slots and [`NodeHandle`](super::NodeHandle)s.
+    ///
+    /// The body starts with head, tail, the number of free slots and the free
+    /// slots in order of reuse. Every slot follows with a tag (`0` vacant,
+    /// `1` occupied) and its generation, occupied slots add their next and prev
+    /// slot and the element like in the sequential body.
+    pub fn write_arena_snapshot<W, E>(&self, writer: W, encode: E) -> io::Result<()>
+    where
+        W: Write,
+        E: Fn(&T, &mut dyn Write) -> io::Result<()>,
+    {
+        let mut snapshot_writer = SnapshotWriter::new(writer);
+        snapshot_writer.write_header(FLAG_ARENA_LAYOUT, self.len(), self.all_elements.len())?;
+
+        snapshot_writer.write_index(self.head_index)?;
+        snapshot_writer.write_index(self.tail_index)?;
+        snapshot_writer.write_index(self.free_indices.len())?;
+        for free_index in &self.free_indices {
+            snapshot_writer.write_index(*free_index)?;
+        }
+
+        let mut element_buffer = Vec::new();
+        for entry in &self.all_elements {
+            match &entry.hold_data {
+                SlotData::Occupied(hold_data) => {
+                    snapshot_writer.write_bytes(&[OCCUPIED_SLOT])?;
+                    snapshot_writer.write_index(entry.generation)?;
+                    snapshot_writer.write_index(entry.next_index)?;
+                    snapshot_writer.write_index(entry.prev_index)?;
+                    snapshot_writer.write_element(&**hold_data, &encode, &mut element_buffer)?;
+                }
+                SlotData::Vacant => {
+                    snapshot_writer.write_bytes(&[VACANT_SLOT])?;
+                    snapshot_writer.write_index(entry.generation)?;
+                }
+            }
+        }
+        snapshot_writer.finish()
+    }
+
+    /// Reads a snapshot written by [`write_snapshot`](List::write_snapshot) or
+    /// [`write_arena_snapshot`](List::write_arena_snapshot), `decode` reads a
+    /// single element from its bytes.
+    ///
+    /// Snapshots with a wrong magic, an unknown version, a checksum mismatch or
+    /// an inconsistent arena are rejected with [`io::ErrorKind::InvalidData`].
+    pub fn read_snapshot<R, D>(reader: R, mut decode: D) -> io::Result<List<T, F>>
+    where
+        T: Sized,
+        R: Read,
+        D: FnMut(&mut dyn Read) -> io::Result<T>,
+    {
+        let mut snapshot_reader = SnapshotReader::new(reader);
+
+        let mut magic = [0; 4];
+        snapshot_reader.read_bytes(&mut magic)?;
+        if magic != SNAPSHOT_MAGIC {
+            return Err(invalid_data("not a list snapshot"));
+        }
+        let version = snapshot_reader.read_u16()?;
+        if version != SNAPSHOT_VERSION {
+            return Err(invalid_data(format!(
+                "unsupported snapshot version {version}"
+            )));
+        }
+        let flags = snapshot_reader.read_u16()?;
+        let list_len = snapshot_reader.read_index()?;
+        let arena_size = snapshot_reader.read_index()?;
+
+        let mut element_buffer = Vec::new();
+        let new_list = match flags {
+            0 if arena_size == list_len => {
+                let mut new_list = List::default();
+                for _ in 0..list_len {
+                    let element = snapshot_reader.read_element(&mut decode, &mut element_buffer)?;
+                    new_list.append(element);
+                }
+                new_list
+            }
+            FLAG_ARENA_LAYOUT if arena_size >= list_len => {
+                let head_index = snapshot_reader.read_index()?;
+                let tail_index = snapshot_reader.read_index()?;
+                let free_count = snapshot_reader.read_index()?;
+                if free_count != arena_size - list_len {
+                    return Err(invalid_data("free slots don't match the length"));
+                }
+                let free_indices = (0..free_count)
+                    .map(|_| snapshot_reader.read_index())
+                    .collect::<io::Result<VecDeque<usize>>>()?;
+
+                let mut all_elements = Vec::new();
+                for slot_index in 0..arena_size {
+                    let mut slot_tag = [0];
+                    snapshot_reader.read_bytes(&mut slot_tag)?;
+                    let generation = snapshot_reader.read_index()?;
+                    let (hold_data, next_index, prev_index) = match slot_tag[0] {
+                        VACANT_SLOT => (SlotData::Vacant, slot_index, slot_index),
+                        OCCUPIED_SLOT => {
+                            let next_index = snapshot_reader.read_index()?;
+                            let prev_index = snapshot_reader.read_index()?;
+                            let element =
+                                snapshot_reader.read_element(&mut decode, &mut element_buffer)?;
+                            (SlotData::Occupied(F::new(element)), next_index, prev_index)
+                        }
+                        slot_tag => {
+                            return Err(invalid_data(format!("unknown slot tag {slot_tag}")));
+                        }
+                    };
+                    all_elements.push(ListEntry {
+                        hold_data,
+                        next_index,
+                        prev_index,
+                        generation,
+                    });
+                }
+
+                // the handles of the snapshot list may still be around, so
+                // new generations have to be greater than the stored ones
+                let next_generation = all_elements
+                    .iter()
+                    .map(|entry| entry.generation.wrapping_add(1))
+                    .max()
+                    .unwrap_or(0);
+                let new_list = List {
+                    head_index,
+                    tail_index,
+                    all_elements,
+                    free_indices,
+                    next_generation,
+                    ..List::default()
+                };
+                if let Err(corruption) = new_list.validate() {
+                    return Err(invalid_data(format!(
+                        "inconsistent arena layout: {corruption}"
+                    )));
+                }
+                new_list
+            }
+            _ => return Err(invalid_data("inconsistent snapshot header")),
+        };
+
+        snapshot_reader.finish()?;
+        Ok(new_list)
+    }
+}
+
+struct SnapshotWriter<W> {
+    writer: W,
+    checksum: Fnv1a,
+}
+
+impl<W: Write> SnapshotWriter<W> {
+    fn new(writer: W) -> SnapshotWriter<W> {
+        SnapshotWriter {
+            writer,
+            checksum: Fnv1a::new(),
+        }
+    }
+
+    fn write_header(&mut self, flags: u16, list_len: usize, arena_size: usize) -> io::Result<()> {
+        self.write_bytes(&SNAPSHOT_MAGIC)?;
+        self.write_bytes(&SNAPSHOT_VERSION.to_le_bytes())?;
+        self.write_bytes(&flags.to_le_bytes())?;
+        self.write_index(list_len)?;
+        self.write_index(arena_size)
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.checksum.update(bytes);
+        self.writer.write_all(bytes)
+    }
+
+    fn write_index(&mut self, index: usize) -> io::Result<()> {
+        self.write_bytes(&(index as u64).to_le_bytes())
+    }
+
+    // elements are length prefixed, so a decoder can never read past its element
+    fn write_element<T, E>(
+        &mut self,
+        element: &T,
+        encode: &E,
+        buffer: &mut Vec<u8>,
+    ) -> io::Result<()>
+    where
+        T: ?Sized,
+        E: Fn(&T, &mut dyn Write) -> io::Result<()>,
+    {
+        buffer.clear();
+        encode(element, buffer)?;
+        self.write_index(buffer.len())?;
+        self.write_bytes(buffer)
+    }
+
+    fn finish(mut self) -> io::Result<()> {
+        let checksum = self.checksum.finish();
+        self.writer.write_all(&checksum.to_le_bytes())?;
+        self.writer.flush()
+    }
+}
+
+struct SnapshotReader<R> {
+    reader: R,
+    checksum: Fnv1a,
+}
+
+impl<R: Read> SnapshotReader<R> {
+    fn new(reader: R) -> SnapshotReader<R> {
+        SnapshotReader {
+            reader,
+            checksum: Fnv1a::new(),
+        }
+    }
+
+    fn read_bytes(&mut self, bytes: &mut [u8]) -> io::Result<()> {
+        self.reader.read_exact(bytes)?;
+        self.checksum.update(bytes);
+        Ok(())
+    }
+
+    fn read_u16(&mut self) -> io::Result<u16> {
+        let mut bytes = [0; 2];
+        self.read_bytes(&mut bytes)?;
+        Ok(u16::from_le_bytes(bytes))
+    }
+
+    fn read_index(&mut self) -> io::Result<usize> {
+        let mut bytes = [0; 8];
+        self.read_bytes(&mut bytes)?;
+        usize::try_from(u64::from_le_bytes(bytes)).map_err(|_| invalid_data("index exceeds usize"))
+    }
+
+    fn read_element<T, D>(&mut self, decode: &mut D, buffer: &mut Vec<u8>) -> io::Result<T>
+    where
+        D: FnMut(&mut dyn Read) -> io::Result<T>,
+    {
+        let element_len = self.read_index()?;
+        // read through take, a corrupt length must not allocate up front
+        buffer.clear();
+        (&mut self.reader)
+            .take(element_len as u64)
+            .read_to_end(buffer)?;
+        if buffer.len() != element_len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        self.checksum.update(buffer);
+        decode(&mut buffer.as_slice())
+    }
+
+    fn finish(mut self) -> io::Result<()> {
+        let expected_checksum = self.checksum.finish();
+        let mut bytes = [0; 8];
+        self.reader.read_exact(&mut bytes)?;
+        match u64::from_le_bytes(bytes) == expected_checksum {
+            true => Ok(()),
+            false => Err(invalid_data("snapshot checksum mismatch")),
+        }
+    }
+}
+
+/// 64 bit FNV-1a hash, small and good enough to detect corrupted snapshots.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Fnv1a {
+        Fnv1a(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+fn invalid_data<M: Into<String>>(message: M) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
@@ -0,0 +1,170 @@
+use std::cmp::Ordering;
+
+use super::List;
+use crate::pointer_family::PointerFamily;
+
+// Marks the end of the chain while the self loops of the tail are broken up
+const NO_INDEX: usize = usize::MAX;
+
+impl<T: ?Sized, F: PointerFamily> List<T, F> {
+    /// Sorts the list stable in ascending order.
+    ///
+    /// The elements stay in their slots, only the links are rewritten, so
+    /// [`NodeHandle`](super::NodeHandle)s and shared pointers stay valid.
+    ///
+    /// ```
+    /// use container::List;
+    ///
+    /// let mut linked_list = List::from_array([3, 1, 2]);
+    /// let handle = linked_list.handle_at(0).unwrap();
+    /// linked_list.sort();
+    ///
+    /// assert_eq!(linked_list, List::from_array([1, 2, 3]));
+    /// assert_eq!(linked_list.get_by_handle(handle), Some(&3));
+    /// ```
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    pub fn sort_by_key<K, G>(&mut self, mut key_fn: G)
+    where
+        K: Ord,
+        G: FnMut(&T) -> K,
+    {
+        self.sort_by(|left, right| key_fn(left).cmp(&key_fn(right)));
+    }
+
+    /// Same as [`sort_by`](List::sort_by), relinking a list is stable without
+    /// any additional cost.
+    pub fn sort_unstable_by<C>(&mut self, compare: C)
+    where
+        C: FnMut(&T, &T) -> Ordering,
+    {
+        self.sort_by(compare);
+    }
+
+    /// Sorts the list stable with a bottom-up merge sort on the links, which
+    /// needs O(n log n) comparisons and no additional memory.
+    pub fn sort_by<C>(&mut self, mut compare: C)
+    where
+        C: FnMut(&T, &T) -> Ordering,
+    {
+        if self.len() < 2 {
+            return;
+        }
+
+        let mut sorted_head = self.head_index;
+        self.all_elements[self.tail_index].next_index = NO_INDEX;
+
+        // merge runs of run_len elements pairwise until a single run is left
+        let mut run_len = 1;
+        loop {
+            let mut left_index = sorted_head;
+            let mut merged_tail = NO_INDEX;
+            let mut merge_count = 0;
+
+            while left_index != NO_INDEX {
+                merge_count += 1;
+
+                let mut right_index = left_index;
+                let mut left_len = 0;
+                while left_len < run_len && right_index != NO_INDEX {
+                    left_len += 1;
+                    right_index = self.all_elements[right_index].next_index;
+                }
+                let mut right_len = run_len;
+
+                while left_len > 0 || (right_len > 0 && right_index != NO_INDEX) {
+                    let take_left = match (left_len, right_len > 0 && right_index != NO_INDEX) {
+                        (0, _) => false,
+                        (_, false) => true,
+                        // ties are taken from the left run, which keeps the sort stable
+                        _ => {
+                            compare(
+                                self.all_elements[left_index].data(),
+                                self.all_elements[right_index].data(),
+                            ) != Ordering::Greater
+                        }
+                    };
+
+                    let merged_index;
+                    if take_left {
+                        merged_index = left_index;
+                        left_index = self.all_elements[left_index].next_index;
+                        left_len -= 1;
+                    } else {
+                        merged_index = right_index;
+                        right_index = self.all_elements[right_index].next_index;
+                        right_len -= 1;
+                    }
+
+                    match merged_tail {
+                        NO_INDEX => sorted_head = merged_index,
+                        _ => self.all_elements[merged_tail].next_index = merged_index,
+                    }
+                    merged_tail = merged_index;
+                }
+
+                left_index = right_index;
+            }
+
+            self.all_elements[merged_tail].next_index = NO_INDEX;
+            if merge_count <= 1 {
+                break;
+            }
+            run_len *= 2;
+        }
+
+        self.relink_from_next(sorted_head);
+    }
+
+    /// Reverses the order of the elements in O(n) by swapping the links of
+    /// every element, no element is moved or cloned and handles stay valid.
+    ///
+    /// ```
+    /// use container::List;
+    ///
+    /// let mut linked_list = List::from_array([1, 2, 3]);
+    /// linked_list.reverse();
+    ///
+    /// assert_eq!(linked_list, List::from_array([3, 2, 1]));
+    /// ```
+    pub fn reverse(&mut self) {
+        if self.is_empty() {
+            return;
+        }
+
+        // the self loops of head and tail swap over together with the links
+        for entry in self.all_elements.iter_mut() {
+            if entry.is_occupied() {
+                std::mem::swap(&mut entry.next_index, &mut entry.prev_index);
+            }
+        }
+        std::mem::swap(&mut self.head_index, &mut self.tail_index);
+        self.position_cache.invalidate();
+        self.check_invariants();
+    }
+
+    /// Rebuilds the prev links, head, tail and self loops from a chain of next
+    /// links starting at `new_head` and ending in `NO_INDEX`.
+    fn relink_from_next(&mut self, new_head: usize) {
+        self.position_cache.invalidate();
+        self.head_index = new_head;
+        let mut prev_index = new_head;
+        let mut slot_index = new_head;
+
+        while slot_index != NO_INDEX {
+            let entry = &mut self.all_elements[slot_index];
+            entry.prev_index = prev_index;
+            prev_index = slot_index;
+            slot_index = entry.next_index;
+        }
+
+        self.tail_index = prev_index;
+        self.all_elements[prev_index].next_index = prev_index;
+        self.check_invariants();
+    }
+}
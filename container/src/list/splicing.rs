@@ -0,0 +1,233 @@
+use std::collections::VecDeque;
+
+use super::{List, ListEntry, SlotData};
+use crate::pointer_family::PointerFamily;
+
+impl<T: ?Sized, F: PointerFamily> List<T, F> {
+    /// Moves all elements of `other` to the end of the list.
+    ///
+    /// The arena of `other` is merged into this one in a single pass, the
+    /// elements are neither cloned nor pushed one by one. Elements of `self`
+    /// keep their slots, so their [`NodeHandle`](super::NodeHandle)s stay valid.
+    ///
+    /// ```
+    /// use container::List;
+    ///
+    /// let mut linked_list = List::from_array([0, 1]);
+    /// linked_list.append_list(List::from_array([2, 3]));
+    ///
+    /// assert_eq!(linked_list, List::from_array([0, 1, 2, 3]));
+    /// ```
+    pub fn append_list(&mut self, other: List<T, F>) {
+        if self.all_elements.is_empty() && self.next_generation == 0 {
+            // nothing to keep and no handles issued, take over the arena of
+            // other as it is
+            let position_cache = self.position_cache.fresh();
+            let compaction_policy = self.compaction_policy;
+            *self = other;
+            self.compaction_policy = compaction_policy;
+            self.position_cache = position_cache;
+            return;
+        }
+
+        let was_empty = self.is_empty();
+        if let Some((first_index, last_index)) = self.absorb_arena(other) {
+            match was_empty {
+                true => {
+                    self.head_index = first_index;
+                    self.tail_index = last_index;
+                }
+                false => {
+                    self.all_elements[self.tail_index].next_index = first_index;
+                    self.all_elements[first_index].prev_index = self.tail_index;
+                    self.tail_index = last_index;
+                }
+            }
+        }
+        self.check_invariants();
+    }
+
+    /// Moves all elements of `other` in front of the element at index `at`,
+    /// `at == len()` appends them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len()`.
+    pub fn splice(&mut self, at: usize, other: List<T, F>) {
+        let list_len = self.len();
+        assert!(at <= list_len, "Index {at} out of range!");
+        if at == list_len {
+            self.append_list(other);
+            return;
+        }
+
+        let referenced_index = self.slot_at(at).expect("index is checked above");
+        if let Some((first_index, last_index)) = self.absorb_arena(other) {
+            if referenced_index == self.head_index {
+                self.head_index = first_index;
+            } else {
+                let prev_index = self.all_elements[referenced_index].prev_index;
+                self.all_elements[prev_index].next_index = first_index;
+                self.all_elements[first_index].prev_index = prev_index;
+            }
+            self.all_elements[last_index].next_index = referenced_index;
+            self.all_elements[referenced_index].prev_index = last_index;
+        }
+        self.check_invariants();
+    }
+
+    /// Splits the list in two at the given index. Returns a new list with the
+    /// elements from `at` on, `self` keeps the elements in front of `at`.
+    ///
+    /// Only the shorter part is moved, it takes O(min(at, len - at)). The
+    /// longer part keeps the arena and with it the
+    /// [`NodeHandle`](super::NodeHandle)s of its elements. So if the part in
+    /// front of `at` is the shorter one, the handles to the elements staying
+    /// in `self` become stale, while the returned list accepts the handles to
+    /// its elements issued by `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len()`.
+    ///
+    /// ```
+    /// use container::List;
+    ///
+    /// let mut linked_list = List::from_array([0, 1, 2, 3]);
+    /// let second_half = linked_list.split_off(2);
+    ///
+    /// assert_eq!(linked_list, List::from_array([0, 1]));
+    /// assert_eq!(second_half, List::from_array([2, 3]));
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> List<T, F> {
+        let list_len = self.len();
+        assert!(at <= list_len, "Index {at} out of range!");
+
+        if at == 0 {
+            let compaction_policy = self.compaction_policy;
+            let mut split_list = std::mem::take(self);
+            self.compaction_policy = compaction_policy;
+            self.position_cache = split_list.position_cache.fresh();
+            split_list.compaction_policy = compaction_policy;
+            return split_list;
+        }
+
+        let split_list = match self.slot_at(at) {
+            Some(first_index) => self.split_off_slot(first_index),
+            None => List::default(),
+        };
+        self.apply_compaction_policy();
+        split_list
+    }
+
+    /// Splits the list in front of `first_index`, which must not be the head.
+    /// `self` keeps the elements in front, the rest is returned.
+    ///
+    /// Only the shorter part is moved into a new arena, the longer one keeps
+    /// the old arena. So a split leaves at most as many vacant slots as the
+    /// shorter part had elements. Handles stay valid for the part keeping the
+    /// old arena, the other part gets new slots.
+    pub(super) fn split_off_slot(&mut self, first_index: usize) -> List<T, F> {
+        let last_prefix_index = self.all_elements[first_index].prev_index;
+
+        // walk both parts at once until the shorter one ends, on a tie the
+        // elements in front keep their slots
+        let (mut prefix_probe, mut suffix_probe) = (self.head_index, first_index);
+        let mut moved_len = 1;
+        let prefix_is_shorter = loop {
+            if suffix_probe == self.tail_index {
+                break false;
+            }
+            if prefix_probe == last_prefix_index {
+                break true;
+            }
+            prefix_probe = self.all_elements[prefix_probe].next_index;
+            suffix_probe = self.all_elements[suffix_probe].next_index;
+            moved_len += 1;
+        };
+
+        match prefix_is_shorter {
+            true => {
+                let prefix_list = self.take_chain(self.head_index, moved_len);
+                self.head_index = first_index;
+                self.all_elements[first_index].prev_index = first_index;
+                self.check_invariants();
+                std::mem::replace(self, prefix_list)
+            }
+            false => {
+                let suffix_list = self.take_chain(first_index, moved_len);
+                self.tail_index = last_prefix_index;
+                self.all_elements[last_prefix_index].next_index = last_prefix_index;
+                self.check_invariants();
+                suffix_list
+            }
+        }
+    }
+
+    /// Vacates `chain_len` linked slots from `first_index` on and returns their
+    /// elements as a new, compact list. The links around the chain are left to
+    /// the caller.
+    fn take_chain(&mut self, first_index: usize, chain_len: usize) -> List<T, F> {
+        // the slots of the new list must not match handles issued by this one
+        let new_generation = self.fresh_generation();
+        let mut chain_elements = Vec::with_capacity(chain_len);
+        let mut slot_index = first_index;
+        for new_index in 0..chain_len {
+            let next_index = self.all_elements[slot_index].next_index;
+            chain_elements.push(ListEntry {
+                hold_data: SlotData::Occupied(self.vacate(slot_index)),
+                next_index: usize::min(new_index + 1, chain_len - 1),
+                prev_index: new_index.saturating_sub(1),
+                generation: new_generation,
+            });
+            slot_index = next_index;
+        }
+
+        List {
+            head_index: 0,
+            tail_index: chain_len - 1,
+            all_elements: chain_elements,
+            free_indices: VecDeque::new(),
+            compaction_policy: self.compaction_policy,
+            position_cache: self.position_cache.fresh(),
+            pending_remap: None,
+            next_generation: self.next_generation,
+        }
+    }
+
+    /// Appends the arena of `other` to this one and returns the slots of its
+    /// first and last element, which are not yet linked to the elements of `self`.
+    /// The absorbed slots get a fresh generation, stale handles into `self`
+    /// must not match them.
+    fn absorb_arena(&mut self, other: List<T, F>) -> Option<(usize, usize)> {
+        if other.is_empty() {
+            return None;
+        }
+
+        self.position_cache.invalidate();
+        let slot_offset = self.all_elements.len();
+        let absorbed_generation = self.fresh_generation();
+        let List {
+            head_index,
+            tail_index,
+            all_elements,
+            free_indices,
+            ..
+        } = other;
+
+        self.all_elements
+            .extend(all_elements.into_iter().map(|mut entry| {
+                entry.next_index += slot_offset;
+                entry.prev_index += slot_offset;
+                entry.generation = absorbed_generation;
+                entry
+            }));
+        self.free_indices.extend(
+            free_indices
+                .into_iter()
+                .map(|free_index| free_index + slot_offset),
+        );
+
+        Some((head_index + slot_offset, tail_index + slot_offset))
+    }
+}
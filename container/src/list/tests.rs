@@ -1,3 +1,5 @@
+use std::rc::Rc;
+
 use super::*;
 
 macro_rules! assert_some_rc_eq {
@@ -138,3 +140,1084 @@ fn tc5_test_remove_at() {
     test_list.remove_at(0);
     assert_none!(test_list.at(0));
 }
+
+#[test]
+fn tc6_insert_before_middle() {
+    let mut test_list = List::from_array([0, 1, 2]);
+
+    test_list.insert_before(2, 5);
+    test_list.insert_before(1, 4);
+
+    let collected: Vec<i32> = test_list.iter().map(|rc_val| *rc_val).collect();
+    assert_eq!(collected, [0, 4, 1, 5, 2]);
+}
+
+#[test]
+fn tc7_remove_tail_then_append() {
+    let mut test_list = List::from_array([0, 1, 2]);
+
+    assert_some_rc_eq!(test_list.remove_at(2), 2);
+    test_list.append(3);
+    test_list.insert_before(0, -1);
+    test_list.append(4);
+
+    let collected: Vec<i32> = test_list.iter().map(|rc_val| *rc_val).collect();
+    assert_eq!(collected, [-1, 0, 1, 3, 4]);
+
+    while test_list.remove_at(0).is_some() {}
+    assert!(test_list.is_empty());
+    assert_none!(test_list.iter().next());
+
+    test_list.append(7);
+    assert_some_rc_eq!(test_list.head(), 7);
+    assert_eq!(test_list.len(), 1);
+}
+
+#[test]
+fn tc8_cursor_remove_while_iterating() {
+    let mut test_list = List::from_array([0, 1, 2, 3, 4, 5]);
+
+    let mut cursor = test_list.iter_mut();
+    while let Some(rc_val) = cursor.next() {
+        if *rc_val % 2 == 0 {
+            cursor.remove_current();
+        }
+    }
+
+    let collected: Vec<i32> = test_list.iter().map(|rc_val| *rc_val).collect();
+    assert_eq!(collected, [1, 3, 5]);
+    assert_eq!(test_list[2], 5);
+}
+
+#[test]
+fn tc9_cursor_insert_and_move_back() {
+    let mut test_list = List::from_array([0, 1, 2]);
+
+    let mut cursor = test_list.pos_mut(1).unwrap();
+    cursor.insert_before(10);
+    cursor.insert_after(11);
+    assert_some_rc_eq!(cursor.prev(), 10);
+    assert_some_rc_eq!(cursor.prev(), 0);
+    assert_none!(cursor.prev());
+    // moving back from the head restarts at the head
+    assert_some_rc_eq!(cursor.next(), 0);
+
+    let collected: Vec<i32> = test_list.iter().map(|rc_val| *rc_val).collect();
+    assert_eq!(collected, [0, 10, 1, 11, 2]);
+
+    let mut const_cursor = test_list.pos(4).unwrap();
+    assert_some_rc_eq!(const_cursor.prev(), 11);
+    assert_some_rc_eq!(const_cursor.next(), 2);
+}
+
+#[test]
+fn tc10_iter_ref_keeps_ref_count() {
+    let shared_element = Rc::new(5);
+    let mut test_list = List::from_array([1, 2]);
+    test_list.append_shared(Rc::clone(&shared_element));
+    test_list.remove_at(0);
+
+    let mut ref_iter = test_list.iter_ref();
+    assert_eq!(ref_iter.size_hint(), (2, Some(2)));
+    assert_eq!(ref_iter.next(), Some(&2));
+    assert_eq!(Rc::strong_count(&shared_element), 2);
+    assert_eq!(ref_iter.next(), Some(&5));
+    assert_eq!(ref_iter.next(), None);
+
+    let collected: Vec<Rc<i32>> = test_list.iter_rc().collect();
+    assert_eq!(Rc::strong_count(&shared_element), 3);
+    assert_eq!(collected, [Rc::new(2), Rc::new(5)]);
+}
+
+#[test]
+fn tc11_mutable_element_access() {
+    let shared_element = Rc::new(3);
+    let mut test_list = List::from_array([1, 2]);
+    test_list.append_shared(Rc::clone(&shared_element));
+
+    *test_list.get_mut(0).unwrap() = 10;
+    assert_none!(test_list.get_mut(2));
+    assert_none!(test_list.get_mut(3));
+
+    test_list[1] += 5;
+    test_list[2] += 5;
+    assert_eq!(*shared_element, 3);
+    assert_eq!(Rc::strong_count(&shared_element), 1);
+
+    let collected: Vec<i32> = test_list.iter_ref().copied().collect();
+    assert_eq!(collected, [10, 7, 8]);
+
+    test_list.insert_before(0, 0);
+    test_list.remove_at(1);
+    for element in test_list.values_mut() {
+        *element *= 2;
+    }
+    let collected: Vec<i32> = test_list.iter_ref().copied().collect();
+    assert_eq!(collected, [0, 14, 16]);
+
+    let mut cursor = test_list.pos_mut(1).unwrap();
+    *cursor.get_mut().unwrap() = 1;
+    *cursor.make_mut().unwrap() += 1;
+    assert_eq!(test_list[1], 2);
+}
+
+#[test]
+fn tc12_node_handles() {
+    let mut test_list = List::from_array([0, 1]);
+
+    let handle_two = test_list.append_with_handle(2);
+    let handle_minus = test_list.insert_before_with_handle(0, -1).unwrap();
+    assert_none!(test_list.insert_before_with_handle(5, 9));
+    assert_eq!(test_list.handle_at(3), Some(handle_two));
+
+    let handle_three = test_list.insert_after_handle(handle_two, 3).unwrap();
+    test_list.insert_before_handle(handle_minus, -2).unwrap();
+    let collected: Vec<i32> = test_list.iter_ref().copied().collect();
+    assert_eq!(collected, [-2, -1, 0, 1, 2, 3]);
+
+    *test_list.get_mut_by_handle(handle_three).unwrap() = 4;
+    assert_some_rc_eq!(test_list.remove_by_handle(handle_two), 2);
+    assert!(!test_list.contains_handle(handle_two));
+    assert_none!(test_list.get_by_handle(handle_two));
+    assert_none!(test_list.remove_by_handle(handle_two));
+
+    // the freed slot is reused, the stale handle must not see the new element
+    let handle_five = test_list.append_with_handle(5);
+    assert_eq!(handle_five.slot_index, handle_two.slot_index);
+    assert_none!(test_list.get_by_handle(handle_two));
+    assert_none!(test_list.insert_after_handle(handle_two, 6));
+    assert_eq!(test_list.get_by_handle(handle_five), Some(&5));
+    assert_some_rc_eq!(test_list.at_handle(handle_three), 4);
+}
+
+#[test]
+fn tc13_remove_without_default() {
+    struct NoDefault(String);
+
+    let mut test_list = List::new();
+    test_list.append(NoDefault("first".to_string()));
+    test_list.append(NoDefault("second".to_string()));
+    test_list.append(NoDefault("third".to_string()));
+
+    let removed = test_list.remove_at(1).unwrap();
+    assert_eq!(removed.0, "second");
+    // the vacant slot does not keep a reference to the removed element
+    assert_eq!(Rc::strong_count(&removed), 1);
+
+    let removed_head = test_list.head_iter_mut().unwrap().remove().unwrap();
+    assert_eq!(removed_head.0, "first");
+    assert_eq!(test_list.len(), 1);
+    assert_eq!(test_list[0].0, "third");
+
+    let mut dyn_list: List<dyn Fn() -> i32> = List::new();
+    dyn_list.append_shared(Rc::new(|| 1));
+    dyn_list.append_shared(Rc::new(|| 2));
+    assert_eq!(dyn_list.remove_at(0).map(|f| f()), Some(1));
+    assert_eq!(dyn_list[0](), 2);
+}
+
+#[test]
+fn tc14_pointer_families() {
+    use std::sync::Arc;
+    use std::thread;
+
+    fn assert_send_sync<S: Send + Sync>() {}
+    assert_send_sync::<SyncList<String>>();
+
+    let shared_element = Arc::new(3);
+    let mut sync_list = SyncList::from_array_in([1, 2]);
+    sync_list.append_shared(Arc::clone(&shared_element));
+    sync_list.insert_before(0, 0);
+    assert_eq!(sync_list.remove_at(1).as_deref(), Some(&1));
+
+    let handle = thread::spawn(move || {
+        let collected: Vec<i32> = sync_list.iter_ref().copied().collect();
+        (collected, sync_list.at(2))
+    });
+    let (collected, last_element) = handle.join().unwrap();
+    assert_eq!(collected, [0, 2, 3]);
+    assert!(last_element.is_some_and(|arc_val| Arc::ptr_eq(&arc_val, &shared_element)));
+
+    let mut owned_list: OwnedList<String> = OwnedList::new_in();
+    owned_list.append("first".to_string());
+    owned_list.append_shared(Box::new("second".to_string()));
+    owned_list.get_mut(0).unwrap().push('!');
+    assert_eq!(
+        owned_list.remove_at(0).as_deref().map(String::as_str),
+        Some("first!")
+    );
+    assert_eq!(owned_list[0], "second");
+}
+
+#[test]
+fn tc15_compaction() {
+    let mut test_list = List::from_array([0, 1, 2, 3, 4, 5]);
+    let handles: Vec<NodeHandle> = (0..6)
+        .map(|index| test_list.handle_at(index).unwrap())
+        .collect();
+
+    test_list.remove_at(4);
+    test_list.remove_at(1);
+    test_list.insert_before(0, -1);
+    test_list.remove_at(2);
+    assert_eq!(test_list.vacant_slots(), 2);
+
+    let remap = test_list.compact();
+    assert_eq!(test_list.vacant_slots(), 0);
+    assert_eq!(test_list.all_elements.len(), 4);
+    let collected: Vec<i32> = test_list.iter_ref().copied().collect();
+    assert_eq!(collected, [-1, 0, 3, 5]);
+
+    // the physical layout follows the logical order
+    assert_eq!(remap.get(0), Some(1));
+    assert_eq!(remap.get(4), Some(0)); // the slot of 4 was reused by -1
+    assert_eq!(remap.get(1), None);
+    assert_eq!(remap.iter().count(), 4);
+
+    for handle in &handles {
+        assert_none!(test_list.get_by_handle(*handle));
+    }
+    let remapped_five = remap.remap(handles[5]).unwrap();
+    assert_eq!(test_list.get_by_handle(remapped_five), Some(&5));
+    assert_none!(remap.remap(handles[1]));
+    assert_none!(remap.remap(handles[4]));
+
+    test_list.append(6);
+    test_list.insert_before(1, -2);
+    let collected: Vec<i32> = test_list.iter_ref().copied().collect();
+    assert_eq!(collected, [-1, -2, 0, 3, 5, 6]);
+}
+
+#[test]
+fn tc16_automatic_compaction() {
+    let mut test_list = List::from_array([0, 1, 2, 3]);
+    test_list.set_compaction_policy(CompactionPolicy::VacancyRatio(0.5));
+
+    test_list.remove_at(0);
+    test_list.remove_at(0);
+    assert_eq!(test_list.vacant_slots(), 2);
+
+    test_list.remove_at(0);
+    assert_eq!(test_list.vacant_slots(), 0);
+    assert_eq!(test_list.all_elements.len(), 1);
+    assert_eq!(test_list[0], 3);
+
+    test_list.remove_at(0);
+    assert!(test_list.is_empty());
+    test_list.append(4);
+    assert_eq!(test_list[0], 4);
+}
+
+#[test]
+fn tc17_standard_traits() {
+    use std::collections::HashSet;
+
+    let mut test_list: List<i32> = (0..4).collect();
+    test_list.remove_at(1);
+    test_list.extend([4, 5]);
+    test_list.extend([Rc::new(6)]);
+    assert_eq!(format!("{test_list:?}"), "[0, 2, 3, 4, 5, 6]");
+
+    let same_list = List::from_array([0, 2, 3, 4, 5, 6]);
+    assert_eq!(test_list, same_list);
+    assert_ne!(test_list, List::from_array([0, 2, 3]));
+    assert!(List::from_array([0, 2, 3]) < test_list);
+    assert!(List::from_array([0, 3]) > test_list);
+    assert_eq!(
+        List::<i32>::new().cmp(&List::new()),
+        std::cmp::Ordering::Equal
+    );
+
+    let mut hash_set = HashSet::new();
+    hash_set.insert(test_list.clone());
+    assert!(hash_set.contains(&same_list));
+    assert!(!hash_set.contains(&List::default()));
+
+    // a clone shares the elements and keeps the handles valid
+    let handle = test_list.handle_at(1).unwrap();
+    let cloned_list = test_list.clone();
+    assert_eq!(cloned_list.get_by_handle(handle), Some(&2));
+    drop(hash_set);
+    assert_eq!(Rc::strong_count(&cloned_list.at(1).unwrap()), 3);
+
+    let mut sum = 0;
+    for element in &cloned_list {
+        sum += element;
+    }
+    assert_eq!(sum, 20);
+
+    let rc_list: List<i32> = [Rc::new(1), Rc::new(2)].into_iter().collect();
+    assert_eq!(rc_list, List::from_array([1, 2]));
+    let owned_list: OwnedList<String> = ["a", "b"].map(String::from).into_iter().collect();
+    assert_eq!(owned_list.clone(), owned_list);
+}
+
+#[test]
+fn tc18_consuming_iteration() {
+    #[derive(Debug, PartialEq)]
+    struct NoClone(i32);
+
+    let shared_element = Rc::new(vec![3]);
+    let mut test_list = List::from_array([vec![1], vec![2]]);
+    test_list.append_shared(Rc::clone(&shared_element));
+    test_list.remove_at(0);
+
+    let mut element_iter = test_list.clone().into_iter_cloned();
+    assert_eq!(element_iter.size_hint(), (2, Some(2)));
+    assert_eq!(element_iter.next(), Some(vec![2]));
+    assert_eq!(element_iter.next(), Some(vec![3]));
+    assert_none!(element_iter.next());
+
+    drop(element_iter);
+    assert_eq!(Rc::strong_count(&shared_element), 2);
+    assert_eq!(test_list.into_vec(), [vec![2], vec![3]]);
+    assert_eq!(Rc::strong_count(&shared_element), 1);
+
+    let shared_no_clone = Rc::new(NoClone(2));
+    let mut no_clone_list = List::from_array([NoClone(1)]);
+    no_clone_list.append_shared(Rc::clone(&shared_no_clone));
+    let results: Vec<_> = no_clone_list.try_into_iter().collect();
+    assert_eq!(results, [Ok(NoClone(1)), Err(Rc::clone(&shared_no_clone))]);
+
+    // moving out by value never clones, shared elements stay shared
+    let mut no_clone_list = List::from_array([NoClone(3)]);
+    no_clone_list.append_shared(Rc::clone(&shared_no_clone));
+    let mut results = Vec::new();
+    for result in no_clone_list {
+        results.push(result);
+    }
+    assert_eq!(results, [Ok(NoClone(3)), Err(Rc::clone(&shared_no_clone))]);
+    assert_eq!(Rc::strong_count(&shared_no_clone), 3);
+
+    let mut owned_list = OwnedList::default();
+    owned_list.append(NoClone(5));
+    let unwrapped: Vec<NoClone> = owned_list.try_into_iter().map(Result::unwrap).collect();
+    assert_eq!(unwrapped, [NoClone(5)]);
+
+    // boxed elements are moved out without Clone
+    let owned_list: OwnedList<NoClone> = OwnedList::from_array_in([NoClone(6), NoClone(7)]);
+    let mut moved_elements = Vec::new();
+    for element in owned_list {
+        moved_elements.push(element);
+    }
+    assert_eq!(moved_elements, [NoClone(6), NoClone(7)]);
+    let owned_list: OwnedList<NoClone> = OwnedList::from_array_in([NoClone(8), NoClone(9)]);
+    assert_eq!(owned_list.into_iter().next_back(), Some(NoClone(9)));
+    let owned_list: OwnedList<NoClone> = OwnedList::from_array_in([NoClone(10)]);
+    assert_eq!(owned_list.into_vec(), [NoClone(10)]);
+}
+
+#[test]
+fn tc19_sorting() {
+    let mut test_list = List::from_array([(3, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (3, 'e')]);
+    test_list.remove_at(2);
+    test_list.insert_before(0, (2, 'f'));
+    test_list.append((0, 'g'));
+    let handle = test_list.handle_at(1).unwrap();
+    let shared_element = test_list.at(2).unwrap();
+
+    test_list.sort_by_key(|element| element.0);
+    let collected: Vec<(i32, char)> = test_list.iter_ref().copied().collect();
+    assert_eq!(
+        collected,
+        [(0, 'g'), (1, 'b'), (1, 'd'), (2, 'f'), (3, 'a'), (3, 'e')]
+    );
+    assert_eq!(test_list.get_by_handle(handle), Some(&(3, 'a')));
+    assert!(Rc::ptr_eq(&test_list.at(1).unwrap(), &shared_element));
+
+    // the links in both directions are consistent after the relinking
+    let mut cursor = test_list.pos(5).unwrap();
+    assert_some_rc_eq!(cursor.prev(), (3, 'a'));
+    test_list.append((4, 'h'));
+    test_list.remove_at(0);
+    test_list.sort_unstable_by(|left, right| right.cmp(left));
+    let collected: Vec<char> = test_list.iter_ref().map(|element| element.1).collect();
+    assert_eq!(collected, ['h', 'e', 'a', 'f', 'd', 'b']);
+
+    for list_len in 0..40 {
+        let mut number_list: List<i32> = (0..list_len).map(|num| (num * 17) % 11).collect();
+        number_list.sort();
+        let mut expected: Vec<i32> = (0..list_len).map(|num| (num * 17) % 11).collect();
+        expected.sort();
+        assert_eq!(
+            number_list.iter_ref().copied().collect::<Vec<_>>(),
+            expected
+        );
+        assert_eq!(number_list.iter_rc().count(), list_len as usize);
+    }
+}
+
+#[test]
+fn tc20_append_split_splice() {
+    let mut test_list = List::from_array([0, 1, 2]);
+    test_list.remove_at(1);
+    let handle = test_list.handle_at(1).unwrap();
+
+    let mut other_list = List::from_array([10, 11, 12]);
+    other_list.remove_at(0);
+    test_list.append_list(other_list);
+    assert_eq!(test_list, List::from_array([0, 2, 11, 12]));
+    assert_eq!(test_list.get_by_handle(handle), Some(&2));
+    // vacant slots of both arenas are reused
+    assert_eq!(test_list.vacant_slots(), 2);
+    test_list.append(13);
+    test_list.insert_before(0, -1);
+    assert_eq!(test_list.vacant_slots(), 0);
+    assert_eq!(test_list, List::from_array([-1, 0, 2, 11, 12, 13]));
+
+    test_list.splice(2, List::from_array([1, 1]));
+    test_list.splice(0, List::from_array([-2]));
+    test_list.splice(9, List::from_array([14]));
+    test_list.splice(3, List::new());
+    assert_eq!(
+        test_list,
+        List::from_array([-2, -1, 0, 1, 1, 2, 11, 12, 13, 14])
+    );
+
+    let mut tail_list = test_list.split_off(6);
+    assert_eq!(tail_list, List::from_array([11, 12, 13, 14]));
+    assert_eq!(test_list, List::from_array([-2, -1, 0, 1, 1, 2]));
+    assert_eq!(test_list.get_by_handle(handle), Some(&2));
+    tail_list.append(15);
+    test_list.append(3);
+    assert_eq!(tail_list.iter_ref().sum::<i32>(), 65);
+    assert_eq!(test_list.split_off(7), List::new());
+
+    let whole_list = test_list.split_off(0);
+    assert!(test_list.is_empty());
+    assert_eq!(whole_list.len(), 7);
+    test_list.append_list(whole_list);
+    assert_eq!(test_list.len(), 7);
+
+    let mut cursor = test_list.pos_mut(2).unwrap();
+    let cursor_split = cursor.split_at_cursor();
+    assert_eq!(cursor.next(), None);
+    assert_some_rc_eq!(cursor.prev(), -1);
+    assert_eq!(cursor_split, List::from_array([1, 1, 2, 3]));
+    assert_eq!(test_list, List::from_array([-2, -1, 0]));
+
+    let mut empty_list: List<i32> = List::new();
+    empty_list.append_list(List::new());
+    assert!(empty_list.is_empty());
+}
+
+#[test]
+fn tc21_deque_operations() {
+    let mut test_list = List::new();
+    assert_none!(test_list.front());
+    assert_none!(test_list.pop_front());
+    assert_none!(test_list.pop_back());
+
+    test_list.push_front(1);
+    test_list.push_back(2);
+    test_list.push_front(0);
+    test_list.push_front_shared(Rc::new(-1));
+    assert_eq!(test_list.front(), Some(&-1));
+    assert_eq!(test_list.back(), Some(&2));
+
+    *test_list.front_mut().unwrap() -= 1;
+    *test_list.back_mut().unwrap() += 1;
+    assert_eq!(test_list, List::from_array([-2, 0, 1, 3]));
+
+    assert_some_rc_eq!(test_list.pop_back(), 3);
+    assert_some_rc_eq!(test_list.pop_front(), -2);
+    assert_eq!(test_list.back(), Some(&1));
+    test_list.push_back(4);
+    assert_eq!(test_list, List::from_array([0, 1, 4]));
+
+    test_list.truncate(5);
+    assert_eq!(test_list.len(), 3);
+    test_list.truncate(1);
+    assert_eq!(test_list, List::from_array([0]));
+
+    let handle = test_list.handle_at(0).unwrap();
+    test_list.clear();
+    assert!(test_list.is_empty());
+    assert_none!(test_list.back());
+    test_list.push_front(5);
+    assert_none!(test_list.get_by_handle(handle));
+    assert_some_rc_eq!(test_list.pop_back(), 5);
+    assert_none!(test_list.pop_front());
+}
+
+#[test]
+fn tc22_bulk_removal() {
+    let shared_element = Rc::new(8);
+    let mut test_list: List<i32> = (0..8).collect();
+    test_list.append_shared(Rc::clone(&shared_element));
+
+    test_list.retain(|element| element % 3 != 0);
+    assert_eq!(test_list, List::from_array([1, 2, 4, 5, 7, 8]));
+    assert_eq!(test_list.vacant_slots(), 3);
+
+    test_list.retain_mut(|element| {
+        *element *= 10;
+        *element != 20
+    });
+    assert_eq!(test_list, List::from_array([10, 40, 50, 70, 80]));
+    assert_eq!(*shared_element, 8);
+
+    let extracted: Vec<i32> = test_list
+        .extract_if(|element| *element > 45)
+        .map(|rc_val| *rc_val)
+        .collect();
+    assert_eq!(extracted, [50, 70, 80]);
+    assert_eq!(test_list, List::from_array([10, 40]));
+    assert_eq!(test_list.back(), Some(&40));
+
+    let mut dup_list = List::from_array([1, 1, 2, 3, 3, 3, 1, 4, 4]);
+    dup_list.dedup();
+    assert_eq!(dup_list, List::from_array([1, 2, 3, 1, 4]));
+    dup_list.dedup_by_key(|element| element / 2);
+    assert_eq!(dup_list, List::from_array([1, 2, 1, 4]));
+    dup_list.push_back(5);
+    dup_list.dedup_by(|element, kept_element| element > kept_element);
+    assert_eq!(dup_list, List::from_array([1, 1]));
+
+    let mut everything = List::from_array([1, 2, 3]);
+    everything.retain(|_| false);
+    assert!(everything.is_empty());
+    everything.append(4);
+    assert_eq!(everything, List::from_array([4]));
+
+    // boxed elements are modified in place, without Clone
+    #[derive(Debug, PartialEq)]
+    struct NoClone(i32);
+    let mut owned_list: OwnedList<NoClone> = (0..4).map(NoClone).collect();
+    owned_list.retain_mut(|NoClone(value)| {
+        *value *= 10;
+        *value != 20
+    });
+    assert_eq!(
+        owned_list.into_vec(),
+        [NoClone(0), NoClone(10), NoClone(30)]
+    );
+}
+
+#[test]
+fn tc23_double_ended_iteration() {
+    let mut test_list = List::from_array([0, 1, 2, 3, 4]);
+    test_list.remove_at(1);
+    test_list.push_front(9);
+
+    let mut ref_iter = test_list.iter_ref();
+    assert_eq!(ref_iter.len(), 5);
+    assert_eq!(ref_iter.next_back(), Some(&4));
+    assert_eq!(ref_iter.next(), Some(&9));
+    assert_eq!(ref_iter.len(), 3);
+    assert_eq!(ref_iter.rev().collect::<Vec<_>>(), [&3, &2, &0]);
+    assert_eq!(test_list.iter_ref().last(), Some(&4));
+
+    let mut list_pos = test_list.iter();
+    assert_some_rc_eq!(list_pos.next_back(), 4);
+    assert_some_rc_eq!(list_pos.next(), 9);
+    assert_some_rc_eq!(list_pos.next(), 0);
+    assert_some_rc_eq!(list_pos.next_back(), 3);
+    assert_some_rc_eq!(list_pos.next(), 2);
+    assert_none!(list_pos.next_back());
+    assert_none!(list_pos.next());
+    assert_some_rc_eq!(list_pos.prev(), 0);
+    assert_eq!(list_pos.len(), 1);
+    assert_some_rc_eq!(list_pos.next_back(), 2);
+
+    let mut pos_mut = test_list.pos_mut(1).unwrap();
+    assert_eq!(pos_mut.len(), 3);
+    pos_mut.insert_after(7);
+    assert_eq!(pos_mut.len(), 4);
+    assert_some_rc_eq!(pos_mut.next_back(), 4);
+    assert_some_rc_eq!(pos_mut.next(), 7);
+    assert_some!(pos_mut.remove_current());
+    assert_eq!(
+        pos_mut.rev().map(|rc_val| *rc_val).collect::<Vec<_>>(),
+        [3, 2]
+    );
+
+    for element in test_list.values_mut().rev().take(2) {
+        *element *= 10;
+    }
+    assert_eq!(test_list, List::from_array([9, 0, 2, 30, 40]));
+
+    let mut into_iter = test_list.clone().into_iter_cloned();
+    assert_eq!(into_iter.next_back(), Some(40));
+    assert_eq!(into_iter.len(), 4);
+    assert_eq!(into_iter.rev().collect::<Vec<_>>(), [30, 2, 0, 9]);
+
+    let handle = test_list.handle_at(0).unwrap();
+    test_list.reverse();
+    assert_eq!(test_list, List::from_array([40, 30, 2, 0, 9]));
+    assert_eq!(test_list.get_by_handle(handle), Some(&9));
+    test_list.append(1);
+    test_list.push_front(5);
+    assert_eq!(test_list, List::from_array([5, 40, 30, 2, 0, 9, 1]));
+    assert_eq!(test_list.iter_ref().rev().count(), 7);
+}
+
+#[test]
+fn tc24_checked_access() {
+    let mut test_list = List::from_array([1, 2]);
+    test_list.insert_before(2, 3);
+    assert_eq!(test_list, List::from_array([1, 2, 3]));
+
+    assert_eq!(test_list.try_get(0), Ok(&1));
+    assert_eq!(
+        test_list.try_get(3),
+        Err(ListError::OutOfRange { index: 3, len: 3 })
+    );
+    assert_eq!(
+        test_list.try_insert_before(4, 0),
+        Err(ListError::OutOfRange { index: 4, len: 3 })
+    );
+    assert_eq!(test_list.try_insert_before(3, 4), Ok(()));
+    assert_eq!(test_list.back(), Some(&4));
+    assert!(test_list.insert_before_with_handle(4, 5).is_some());
+    assert!(test_list.insert_before_with_handle(9, 5).is_none());
+
+    assert_some_rc_eq!(test_list.try_remove_at(0).ok(), 1);
+    assert_eq!(
+        test_list.try_remove_at(4).err(),
+        Some(ListError::OutOfRange { index: 4, len: 4 })
+    );
+    *test_list.try_make_mut(0).unwrap() = 20;
+    assert_eq!(test_list, List::from_array([20, 3, 4, 5]));
+
+    let mut empty_list: List<i32> = List::new();
+    assert_eq!(empty_list.try_pop_front().err(), Some(ListError::EmptyList));
+    assert_eq!(empty_list.try_pop_back().err(), Some(ListError::EmptyList));
+    assert_eq!(empty_list.try_insert_before(0, 7), Ok(()));
+    assert_eq!(empty_list, List::from_array([7]));
+    assert_eq!(
+        ListError::OutOfRange { index: 3, len: 1 }.to_string(),
+        "index 3 out of range for list of length 1"
+    );
+}
+
+#[test]
+#[should_panic(expected = "index 2 out of range for list of length 1")]
+fn tc25_insert_out_of_range() {
+    let mut test_list = List::from_array([1]);
+    test_list.insert_before(2, 0);
+}
+
+#[test]
+fn tc26_capacity() {
+    let mut test_list = List::with_capacity(8);
+    assert!(test_list.capacity() >= 8);
+    test_list.extend(0..8);
+    let capacity = test_list.capacity();
+    test_list.truncate(2);
+    assert_eq!(test_list.capacity(), capacity);
+
+    test_list.reserve_exact(6);
+    assert_eq!(test_list.capacity(), capacity);
+    test_list.reserve(7);
+    assert!(test_list.capacity() >= 9);
+    assert_eq!(
+        test_list.try_reserve(usize::MAX),
+        Err(ListError::CapacityOverflow)
+    );
+
+    let handle = test_list.handle_at(1).unwrap();
+    let remap = test_list.shrink_to_fit();
+    assert_eq!(test_list.capacity(), 2);
+    assert_eq!(test_list.vacant_slots(), 0);
+    assert_eq!(
+        test_list.get_by_handle(remap.remap(handle).unwrap()),
+        Some(&1)
+    );
+
+    let mut sync_list: SyncList<i32> = SyncList::default();
+    sync_list.reserve_exact(3);
+    assert_eq!(sync_list.capacity(), 3);
+    let owned_list: OwnedList<i32> = OwnedList::with_capacity_in(4);
+    assert!(owned_list.capacity() >= 4);
+    assert!(owned_list.is_empty());
+}
+
+#[test]
+fn tc27_position_cache() {
+    let mut test_list: List<usize> = (0..10).collect();
+    assert!(!test_list.has_position_cache());
+    test_list.set_position_cache(true);
+
+    let forward: Vec<usize> = (0..10).map(|index| test_list[index]).collect();
+    assert_eq!(forward, (0..10).collect::<Vec<_>>());
+    let backward: Vec<usize> = (0..10).rev().map(|index| test_list[index]).collect();
+    assert_eq!(backward, (0..10).rev().collect::<Vec<_>>());
+    // mutable lookups move the finger, the others start from it
+    for index in [3, 4, 6, 5, 9, 0] {
+        *test_list.make_mut(index).unwrap() += 10;
+        assert_eq!(test_list.get(index), Some(&(index + 10)));
+    }
+    for index in [3, 4, 6, 5, 9, 0] {
+        *test_list.get_mut(index).unwrap() -= 10;
+    }
+    assert_eq!(test_list, (0..10).collect());
+    assert_eq!(test_list.get(4), Some(&4));
+    test_list.remove_at(2);
+    assert_eq!(test_list.get(4), Some(&5));
+    test_list.insert_before(3, 20);
+    assert_eq!(test_list.get(3), Some(&20));
+    assert_eq!(test_list.get(4), Some(&4));
+    test_list.reverse();
+    assert_eq!(test_list.get(4), Some(&5));
+    test_list.sort();
+    assert_eq!(test_list.get(5), Some(&6));
+
+    let mut cursor = test_list.pos_mut(5).unwrap();
+    cursor.remove_current();
+    assert_eq!(test_list.get(5), Some(&7));
+
+    let tail_part = test_list.split_off(6);
+    assert!(tail_part.has_position_cache());
+    assert_eq!(tail_part.get(1), Some(&9));
+    assert_eq!(test_list.get(5), Some(&7));
+    test_list.splice(1, tail_part);
+    assert_eq!(test_list, List::from_array([0, 8, 9, 20, 1, 3, 4, 5, 7]));
+    assert_eq!(test_list.get(5), Some(&3));
+
+    let cloned_list = test_list.clone();
+    test_list.compact();
+    assert_eq!(test_list.get(8), Some(&7));
+    assert_eq!(cloned_list.get(2), Some(&9));
+    assert!(cloned_list.has_position_cache());
+
+    test_list.set_position_cache(false);
+    assert!(!test_list.has_position_cache());
+    assert_eq!(test_list.get(3), Some(&20));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn tc28_serde() {
+    let mut test_list = List::from_array([1, 2, 3]);
+    test_list.remove_at(0);
+    test_list.push_front(0);
+
+    let json = serde_json::to_string(&test_list).unwrap();
+    assert_eq!(json, "[0,2,3]");
+    let restored: SyncList<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, SyncList::from([0, 2, 3]));
+    assert!(serde_json::from_str::<List<i32>>("{\"a\":1}").is_err());
+
+    let shared_element = Rc::new(7);
+    let mut shared_list = List::new();
+    shared_list.append_shared(Rc::clone(&shared_element));
+    shared_list.append(7);
+    shared_list.append_shared(Rc::clone(&shared_element));
+    assert_eq!(serde_json::to_string(&shared_list).unwrap(), "[7,7,7]");
+
+    let mut json = Vec::new();
+    serde_shared::serialize(&shared_list, &mut serde_json::Serializer::new(&mut json)).unwrap();
+    assert_eq!(json, b"{\"values\":[7,7],\"order\":[0,1,0]}");
+
+    let restored: List<i32> =
+        serde_shared::deserialize(&mut serde_json::Deserializer::from_slice(&json)).unwrap();
+    assert_eq!(restored, shared_list);
+    let first = restored.at(0).unwrap();
+    assert!(Rc::ptr_eq(&first, &restored.at(2).unwrap()));
+    assert!(!Rc::ptr_eq(&first, &restored.at(1).unwrap()));
+
+    let broken = b"{\"values\":[7],\"order\":[0,1]}";
+    let broken_result: Result<List<i32>, _> =
+        serde_shared::deserialize(&mut serde_json::Deserializer::from_slice(broken));
+    assert!(broken_result.is_err());
+}
+
+#[test]
+fn tc29_snapshots() {
+    use std::io::{ErrorKind, Read, Write};
+
+    fn encode(element: &str, writer: &mut dyn Write) -> std::io::Result<()> {
+        writer.write_all(element.as_bytes())
+    }
+    fn decode(reader: &mut dyn Read) -> std::io::Result<String> {
+        let mut element = String::new();
+        reader.read_to_string(&mut element)?;
+        Ok(element)
+    }
+
+    let mut test_list: List<str> = List::new();
+    for element in ["a", "bc", "", "def"] {
+        test_list.append_shared(Rc::from(element));
+    }
+    test_list.remove_at(1);
+    let handle = test_list.handle_at(2).unwrap();
+
+    let mut sequence = Vec::new();
+    test_list.write_snapshot(&mut sequence, encode).unwrap();
+    assert_eq!(&sequence[..8], b"IRXL\x01\x00\x00\x00");
+    let restored: List<String> = List::read_snapshot(sequence.as_slice(), decode).unwrap();
+    assert_eq!(
+        restored,
+        List::from_array(["a", "", "def"].map(String::from))
+    );
+    assert_eq!(restored.vacant_slots(), 0);
+
+    let mut arena = Vec::new();
+    test_list.write_arena_snapshot(&mut arena, encode).unwrap();
+    let mut restored: List<String> = List::read_snapshot(arena.as_slice(), decode).unwrap();
+    assert_eq!(restored.vacant_slots(), 1);
+    assert_eq!(
+        restored.get_by_handle(handle).map(String::as_str),
+        Some("def")
+    );
+    restored.append(String::from("g"));
+    assert_eq!(restored.handle_at(3).unwrap().slot(), 1);
+
+    let mut corrupted = arena.clone();
+    let last_byte = corrupted.len() - 9;
+    corrupted[last_byte] ^= 1;
+    let error = List::<String>::read_snapshot(corrupted.as_slice(), decode).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+    let truncated = &sequence[..sequence.len() - 3];
+    assert!(List::<String>::read_snapshot(truncated, decode).is_err());
+    let error = List::<String>::read_snapshot(&b"XXXX"[..], decode).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+    // a consistent checksum doesn't help a broken layout, head is moved to slot 1
+    let mut broken_layout: List<String> = List::new();
+    broken_layout.append(String::from("x"));
+    broken_layout.append(String::from("y"));
+    let mut snapshot = Vec::new();
+    broken_layout
+        .write_arena_snapshot(&mut snapshot, |element, writer| encode(element, writer))
+        .unwrap();
+    snapshot[24] = 1;
+    let body_len = snapshot.len() - 8;
+    let mut checksum: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in &snapshot[..body_len] {
+        checksum = (checksum ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3);
+    }
+    snapshot[body_len..].copy_from_slice(&checksum.to_le_bytes());
+    let error = List::<String>::read_snapshot(snapshot.as_slice(), decode).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "inconsistent arena layout: slot 1 links back to 0 instead of 1"
+    );
+}
+
+#[test]
+fn tc30_validate() {
+    let mut test_list = List::from_array([0, 1, 2, 3]);
+    test_list.remove_at(1);
+    test_list.reverse();
+    test_list.push_front(4);
+    assert_eq!(test_list.validate(), Ok(()));
+    assert_eq!(List::<i32>::new().validate(), Ok(()));
+
+    let mut broken = test_list.clone();
+    let tail_index = broken.tail_index;
+    broken.all_elements[tail_index].next_index = 2;
+    assert_eq!(
+        broken.validate(),
+        Err(ListCorruption::TailNotTerminated {
+            tail_index,
+            next_index: 2
+        })
+    );
+
+    let mut broken = test_list.clone();
+    broken.pop_back();
+    broken.free_indices.push_back(0);
+    assert_eq!(
+        broken.validate(),
+        Err(ListCorruption::DuplicateFreeSlot { slot_index: 0 })
+    );
+
+    let mut broken = test_list.clone();
+    broken.free_indices.push_back(0);
+    assert_eq!(
+        broken.validate(),
+        Err(ListCorruption::FreeSlotOccupied { slot_index: 0 })
+    );
+
+    // head 1 -> 3 -> 2 -> 0, let 2 point back to 3 again
+    let mut broken = test_list.clone();
+    broken.all_elements[2].next_index = 3;
+    assert_eq!(
+        broken.validate(),
+        Err(ListCorruption::Cycle {
+            position: 3,
+            slot_index: 3
+        })
+    );
+
+    let mut broken = test_list.clone();
+    broken.all_elements[3].prev_index = 3;
+    assert_eq!(
+        broken.validate(),
+        Err(ListCorruption::BrokenBackLink {
+            slot_index: 3,
+            expected_prev: 1,
+            found_prev: 3
+        })
+    );
+
+    let mut broken = test_list.clone();
+    broken.tail_index = 2;
+    assert_eq!(
+        broken.validate(),
+        Err(ListCorruption::LengthMismatch { counted: 3, len: 4 })
+    );
+
+    let mut broken = test_list;
+    broken.head_index = 9;
+    assert!(matches!(
+        broken.validate(),
+        Err(ListCorruption::EndOutOfBounds { head_index: 9, .. })
+    ));
+    assert_eq!(
+        broken.validate().unwrap_err().to_string(),
+        "head 9 or tail 0 outside of arena of size 4"
+    );
+}
+
+#[test]
+fn tc31_split_keeps_larger_arena() {
+    let mut test_list: List<u32> = (0..1000).collect();
+    let handle = test_list.handle_at(500).unwrap();
+    let tail_list = test_list.split_off(1);
+    assert_eq!(test_list, List::from_array([0]));
+    assert_eq!(test_list.vacant_slots(), 0);
+    assert_eq!(test_list.capacity(), 1);
+    assert_eq!(test_list.get_by_handle(handle), None);
+    // the longer part keeps the arena and with it the handles
+    assert_eq!(tail_list.len(), 999);
+    assert_eq!(tail_list.vacant_slots(), 1);
+    assert_eq!(tail_list.get_by_handle(handle), Some(&500));
+
+    let mut test_list: List<u32> = (0..1000).collect();
+    let short_tail = test_list.split_off(998);
+    assert_eq!(short_tail, List::from_array([998, 999]));
+    assert_eq!(short_tail.vacant_slots(), 0);
+    assert_eq!(test_list.vacant_slots(), 2);
+    assert_eq!(test_list.get_by_handle(handle), Some(&500));
+
+    let mut cursor = test_list.pos_mut(0).unwrap();
+    let cursor_split = cursor.split_at_cursor();
+    assert_eq!(cursor.next(), None);
+    cursor.insert_after(7);
+    assert_some_rc_eq!(cursor.next(), 7);
+    assert_eq!(cursor_split.len(), 997);
+    assert_eq!(cursor_split.vacant_slots(), 3);
+    assert_eq!(test_list, List::from_array([0, 7]));
+}
+
+#[test]
+fn tc32_handles_across_automatic_compaction() {
+    let mut test_list: List<i32> = (0..8).collect();
+    test_list.set_compaction_policy(CompactionPolicy::VacancyRatio(0.3));
+    let kept_handle = test_list.handle_at(7).unwrap();
+    let removed_handles: Vec<NodeHandle> = (0..3)
+        .map(|index| test_list.handle_at(index).unwrap())
+        .collect();
+    assert!(test_list.take_last_remap().is_none());
+
+    // the third removal compacts the arena
+    for removed_handle in removed_handles.iter().copied() {
+        assert!(test_list.remove_by_handle(removed_handle).is_some());
+    }
+    assert_eq!(test_list.vacant_slots(), 0);
+    assert_eq!(test_list.get_by_handle(kept_handle), None);
+    let remap = test_list.take_last_remap().unwrap();
+    assert!(test_list.take_last_remap().is_none());
+    assert_eq!(remap.remap(removed_handles[0]), None);
+    let kept_handle = remap.remap(kept_handle).unwrap();
+    assert_eq!(test_list.get_by_handle(kept_handle), Some(&7));
+
+    // two compactions before taking the remap are combined
+    let middle_handle = test_list.handle_at(2).unwrap();
+    for _ in 0..2 {
+        test_list.pop_front();
+    }
+    test_list.retain(|element| *element != 5);
+    assert_eq!(test_list, List::from_array([6, 7]));
+    let remap = test_list.take_last_remap().unwrap();
+    assert_eq!(remap.remap(middle_handle), None);
+    let kept_handle = remap.remap(kept_handle).unwrap();
+    assert_eq!(test_list.get_by_handle(kept_handle), Some(&7));
+
+    // a manual compaction leads a pending remap into its layout
+    test_list.pop_front();
+    assert!(test_list.take_last_remap().is_some());
+    test_list.append(8);
+    test_list.append(9);
+    test_list.set_compaction_policy(CompactionPolicy::VacancyRatio(0.1));
+    let kept_handle = test_list.handle_at(0).unwrap();
+    test_list.remove_at(2);
+    test_list.compact();
+    let kept_handle = test_list
+        .take_last_remap()
+        .unwrap()
+        .remap(kept_handle)
+        .unwrap();
+    assert_eq!(test_list.get_by_handle(kept_handle), Some(&7));
+}
+
+#[test]
+fn tc33_values_mut_without_clone() {
+    #[derive(Debug, PartialEq)]
+    struct NoClone(i32);
+
+    let mut owned_list: OwnedList<NoClone> = (1..=4).map(NoClone).collect();
+    owned_list.remove_at(1);
+    owned_list.push_front(NoClone(0));
+    for NoClone(value) in owned_list.values_mut() {
+        *value *= 10;
+    }
+    owned_list[3].0 += 1;
+    assert_eq!(
+        owned_list
+            .values_mut()
+            .rev()
+            .map(|element| element.0)
+            .collect::<Vec<_>>(),
+        [41, 30, 10, 0]
+    );
+
+    // both ends meet in the middle of a list with vacant slots
+    let mut test_list: List<u32> = (0..8).collect();
+    test_list.remove_at(2);
+    test_list.remove_at(4);
+    let mut values = test_list.values_mut();
+    *values.next().unwrap() += 100;
+    *values.next_back().unwrap() += 100;
+    assert_eq!(values.len(), 4);
+    for element in values.by_ref() {
+        *element += 1;
+    }
+    assert_none!(values.next_back());
+    assert_eq!(test_list, List::from_array([100, 2, 4, 5, 7, 107]));
+}
+
+#[test]
+fn tc34_stale_handle_after_arena_shrank() {
+    let mut test_list = List::from_array([0, 1, 2, 3]);
+    let handle = test_list.handle_at(3).unwrap();
+    test_list.remove_at(0);
+    test_list.compact();
+    test_list.append(99);
+    assert_none!(test_list.get_by_handle(handle));
+
+    // slots taken over from another list don't match stale handles either
+    let mut test_list = List::from_array([0, 1]);
+    let handle = test_list.handle_at(1).unwrap();
+    test_list.clear();
+    test_list.compact();
+    test_list.append_list(List::from_array([5, 6]));
+    assert_none!(test_list.get_by_handle(handle));
+    assert_eq!(test_list, List::from_array([5, 6]));
+}
+
+#[test]
+fn tc35_stale_handle_after_automatic_compaction() {
+    let mut test_list = List::from_array([0, 1, 2, 3]);
+    test_list.set_compaction_policy(CompactionPolicy::VacancyRatio(0.2));
+    let handle = test_list.handle_at(3).unwrap();
+    test_list.remove_at(3);
+    assert_eq!(test_list.vacant_slots(), 0);
+    test_list.append(42);
+    assert_none!(test_list.get_by_handle(handle));
+    assert_eq!(test_list, List::from_array([0, 1, 2, 42]));
+}
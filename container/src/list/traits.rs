@@ -0,0 +1,143 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use super::{IntoIter, Iter, List, TryIntoIter};
+use crate::pointer_family::{ArcFamily, BoxFamily, PointerFamily, RcFamily, SharedPointerFamily};
+
+// Cloning copies the pointers, so for Rc and Arc both lists share their
+// elements. The arena layout is kept, handles of the original stay valid for the clone.
+impl<T: ?Sized, F: PointerFamily> Clone for List<T, F>
+where
+    F::Pointer<T>: Clone,
+{
+    fn clone(&self) -> Self {
+        List {
+            head_index: self.head_index,
+            tail_index: self.tail_index,
+            all_elements: self.all_elements.clone(),
+            free_indices: self.free_indices.clone(),
+            compaction_policy: self.compaction_policy,
+            position_cache: self.position_cache.clone(),
+            pending_remap: self.pending_remap.clone(),
+            next_generation: self.next_generation,
+        }
+    }
+}
+
+impl<T: ?Sized + fmt::Debug, F: PointerFamily> fmt::Debug for List<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter_ref()).finish()
+    }
+}
+
+impl<T: ?Sized + PartialEq, F: PointerFamily> PartialEq for List<T, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter_ref().eq(other.iter_ref())
+    }
+}
+
+impl<T: ?Sized + Eq, F: PointerFamily> Eq for List<T, F> {}
+
+impl<T: ?Sized + PartialOrd, F: PointerFamily> PartialOrd for List<T, F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter_ref().partial_cmp(other.iter_ref())
+    }
+}
+
+impl<T: ?Sized + Ord, F: PointerFamily> Ord for List<T, F> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter_ref().cmp(other.iter_ref())
+    }
+}
+
+impl<T: ?Sized + Hash, F: PointerFamily> Hash for List<T, F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // the length keeps lists of lists from colliding, same as for slices
+        state.write_usize(self.len());
+        for element in self.iter_ref() {
+            element.hash(state);
+        }
+    }
+}
+
+impl<'a, T: ?Sized, F: PointerFamily> IntoIterator for &'a List<T, F> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_ref()
+    }
+}
+
+/// Moves the elements out of the list without cloning them, like
+/// [`List::try_into_iter`]. Elements that are still shared with other pointers
+/// are yielded as `Err` with the pointer, [`List::into_iter_cloned`] clones them
+/// instead.
+impl<T, F: SharedPointerFamily> IntoIterator for List<T, F> {
+    type Item = Result<T, F::Pointer<T>>;
+    type IntoIter = TryIntoIter<T, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        TryIntoIter::new(self)
+    }
+}
+
+/// Moves the elements out of the list, boxed elements are never shared and
+/// need no `Clone`.
+impl<T> IntoIterator for List<T, BoxFamily> {
+    type Item = T;
+    type IntoIter = IntoIter<T, BoxFamily>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self)
+    }
+}
+
+impl<T, F: PointerFamily> FromIterator<T> for List<T, F> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut new_list = List::default();
+        new_list.extend(iter);
+        new_list
+    }
+}
+
+impl<T, F: PointerFamily> Extend<T> for List<T, F> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let element_iter = iter.into_iter();
+        self.all_elements.reserve(element_iter.size_hint().0);
+        for element in element_iter {
+            self.append(element);
+        }
+    }
+}
+
+// The pointer variants can't be implemented generically over the family, since
+// the compiler can't rule out `F::Pointer<T>` being `T` itself.
+macro_rules! impl_pointer_collect {
+    ($family:ty, $pointer:ident) => {
+        impl<T: ?Sized> FromIterator<$pointer<T>> for List<T, $family> {
+            fn from_iter<I: IntoIterator<Item = $pointer<T>>>(iter: I) -> Self {
+                let mut new_list = List::default();
+                new_list.extend(iter);
+                new_list
+            }
+        }
+
+        impl<T: ?Sized> Extend<$pointer<T>> for List<T, $family> {
+            fn extend<I: IntoIterator<Item = $pointer<T>>>(&mut self, iter: I) {
+                let element_iter = iter.into_iter();
+                self.all_elements.reserve(element_iter.size_hint().0);
+                for element in element_iter {
+                    self.append_shared(element);
+                }
+            }
+        }
+    };
+}
+
+impl_pointer_collect!(RcFamily, Rc);
+impl_pointer_collect!(ArcFamily, Arc);
+impl_pointer_collect!(BoxFamily, Box);
@@ -0,0 +1,122 @@
+use super::{List, ListCorruption};
+use crate::pointer_family::PointerFamily;
+
+impl<T: ?Sized, F: PointerFamily> List<T, F> {
+    /// Checks the structure of the arena: the free slots are vacant and
+    /// distinct, the links form a single chain without cycles from the head to
+    /// the self linked tail, every prev link mirrors its next link and the
+    /// chain covers exactly [`len`](List::len) occupied slots.
+    ///
+    /// A list only built through its own methods is always valid. With the
+    /// `debug-invariants` feature every structural change checks this on its
+    /// own and panics with the report, which costs O(n) per change.
+    ///
+    /// ```
+    /// use container::List;
+    ///
+    /// let mut linked_list = List::from_array([1, 2, 3]);
+    /// linked_list.remove_at(2);
+    ///
+    /// assert_eq!(linked_list.validate(), Ok(()));
+    /// ```
+    pub fn validate(&self) -> Result<(), ListCorruption> {
+        let arena_size = self.all_elements.len();
+        let mut visited = vec![false; arena_size];
+
+        for slot_index in self.free_indices.iter().copied() {
+            match visited.get(slot_index) {
+                None => {
+                    return Err(ListCorruption::FreeSlotOutOfBounds {
+                        slot_index,
+                        arena_size,
+                    });
+                }
+                Some(true) => return Err(ListCorruption::DuplicateFreeSlot { slot_index }),
+                Some(false) if self.all_elements[slot_index].is_occupied() => {
+                    return Err(ListCorruption::FreeSlotOccupied { slot_index });
+                }
+                Some(false) => visited[slot_index] = true,
+            }
+        }
+
+        // the free slots are distinct, so there are exactly len slots left
+        let list_len = self.len();
+        if list_len == 0 {
+            return Ok(());
+        }
+        if self.head_index >= arena_size || self.tail_index >= arena_size {
+            return Err(ListCorruption::EndOutOfBounds {
+                head_index: self.head_index,
+                tail_index: self.tail_index,
+                arena_size,
+            });
+        }
+
+        let mut prev_index = self.head_index;
+        let mut slot_index = self.head_index;
+        for position in 0..list_len {
+            let entry = match self.all_elements.get(slot_index) {
+                None => {
+                    return Err(ListCorruption::LinkOutOfBounds {
+                        position,
+                        slot_index,
+                    });
+                }
+                Some(entry) if !entry.is_occupied() => {
+                    return Err(ListCorruption::VacantSlotLinked {
+                        position,
+                        slot_index,
+                    });
+                }
+                Some(_) if visited[slot_index] => {
+                    return Err(ListCorruption::Cycle {
+                        position,
+                        slot_index,
+                    });
+                }
+                Some(entry) => entry,
+            };
+            if entry.prev_index != prev_index {
+                return Err(ListCorruption::BrokenBackLink {
+                    slot_index,
+                    expected_prev: prev_index,
+                    found_prev: entry.prev_index,
+                });
+            }
+            if slot_index == self.tail_index && position + 1 != list_len {
+                return Err(ListCorruption::LengthMismatch {
+                    counted: position + 1,
+                    len: list_len,
+                });
+            }
+
+            visited[slot_index] = true;
+            prev_index = slot_index;
+            slot_index = entry.next_index;
+        }
+
+        if prev_index != self.tail_index {
+            return Err(ListCorruption::TailNotReached {
+                slot_index: prev_index,
+                tail_index: self.tail_index,
+            });
+        }
+        if slot_index != self.tail_index {
+            return Err(ListCorruption::TailNotTerminated {
+                tail_index: self.tail_index,
+                next_index: slot_index,
+            });
+        }
+        Ok(())
+    }
+
+    /// Validates the list after a structural change if the `debug-invariants`
+    /// feature is enabled, does nothing otherwise.
+    #[inline]
+    pub(super) fn check_invariants(&self) {
+        #[cfg(feature = "debug-invariants")]
+        if let Err(corruption) = self.validate() {
+            panic!("list invariant violated: {corruption}");
+        }
+    }
+}
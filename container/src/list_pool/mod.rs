@@ -0,0 +1,415 @@
+use std::collections::VecDeque;
+
+use crate::list::{ListEntry, NodeHandle, arena};
+use crate::pointer_family::{PointerFamily, RcFamily};
+
+#[cfg(test)]
+mod tests;
+
+/// Many doubly linked lists sharing a single arena.
+///
+/// Every list is identified by a [`PoolListId`], every node by a
+/// [`NodeHandle`]. Since all nodes live in the same arena, a node moves from
+/// one list to another in O(1) by relinking, the element is neither moved in
+/// memory nor cloned and its handle stays valid.
+///
+/// ```
+/// use container::ListPool;
+///
+/// let mut pool = ListPool::new();
+/// let ready = pool.create_list();
+/// let blocked = pool.create_list();
+///
+/// let task = pool.push_back(ready, "task a").unwrap();
+/// pool.push_back(ready, "task b");
+///
+/// pool.move_to_back(task, blocked);
+/// assert_eq!(pool.front(ready), Some(&"task b"));
+/// assert_eq!(pool.front(blocked), Some(&"task a"));
+/// assert_eq!(pool.list_of(task), Some(blocked));
+/// ```
+pub struct ListPool<T: ?Sized, F: PointerFamily = RcFamily> {
+    // the same entries as in a List, every list is a chain of linked entries
+    all_elements: Vec<ListEntry<F::Pointer<T>>>,
+    free_indices: VecDeque<usize>,
+    next_generation: usize,
+    // the list of the node in every slot, only meaningful for occupied slots
+    slot_lists: Vec<usize>,
+    list_heads: Vec<PoolListHead>,
+    free_lists: Vec<usize>,
+}
+
+/// Identifies a list inside of a [`ListPool`].
+///
+/// Like a [`NodeHandle`] the id becomes stale once the list is released, even
+/// if its place is reused for a new list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PoolListId {
+    list_index: usize,
+    generation: usize,
+}
+
+struct PoolListHead {
+    head_index: usize,
+    tail_index: usize,
+    len: usize,
+    generation: usize,
+    in_use: bool,
+}
+
+impl<T: ?Sized, F: PointerFamily> Default for ListPool<T, F> {
+    fn default() -> Self {
+        ListPool {
+            all_elements: Vec::new(),
+            free_indices: VecDeque::new(),
+            next_generation: 0,
+            slot_lists: Vec::new(),
+            list_heads: Vec::new(),
+            free_lists: Vec::new(),
+        }
+    }
+}
+
+impl<T: ?Sized> ListPool<T> {
+    pub fn new() -> ListPool<T> {
+        ListPool::default()
+    }
+}
+
+impl<T: ?Sized, F: PointerFamily> ListPool<T, F> {
+    /// Creates a pool with room for `capacity` nodes, shared by all lists.
+    pub fn with_capacity(capacity: usize) -> ListPool<T, F> {
+        ListPool {
+            all_elements: Vec::with_capacity(capacity),
+            ..ListPool::default()
+        }
+    }
+
+    /// Adds a new, empty list to the pool.
+    pub fn create_list(&mut self) -> PoolListId {
+        let list_index = match self.free_lists.pop() {
+            Some(list_index) => {
+                self.list_heads[list_index].in_use = true;
+                list_index
+            }
+            None => {
+                self.list_heads.push(PoolListHead {
+                    head_index: 0,
+                    tail_index: 0,
+                    len: 0,
+                    generation: 0,
+                    in_use: true,
+                });
+                self.list_heads.len() - 1
+            }
+        };
+
+        PoolListId {
+            list_index,
+            generation: self.list_heads[list_index].generation,
+        }
+    }
+
+    /// Removes a list and drops all of its elements, `false` if the id is stale.
+    pub fn release_list(&mut self, list: PoolListId) -> bool {
+        if !self.contains_list(list) {
+            return false;
+        }
+
+        while self.pop_front(list).is_some() {}
+        let list_head = &mut self.list_heads[list.list_index];
+        list_head.in_use = false;
+        list_head.generation = list_head.generation.wrapping_add(1);
+        self.free_lists.push(list.list_index);
+        true
+    }
+
+    pub fn contains_list(&self, list: PoolListId) -> bool {
+        self.list_heads
+            .get(list.list_index)
+            .is_some_and(|list_head| list_head.in_use && list_head.generation == list.generation)
+    }
+
+    /// Number of elements in the list, `None` if the id is stale.
+    pub fn len(&self, list: PoolListId) -> Option<usize> {
+        self.resolve_list(list)
+            .map(|list_index| self.list_heads[list_index].len)
+    }
+
+    pub fn is_empty(&self, list: PoolListId) -> Option<bool> {
+        self.len(list).map(|list_len| list_len == 0)
+    }
+
+    /// Number of elements in all lists of the pool.
+    pub fn node_count(&self) -> usize {
+        self.all_elements.len() - self.free_indices.len()
+    }
+
+    pub fn push_back(&mut self, list: PoolListId, element: T) -> Option<NodeHandle>
+    where
+        T: Sized,
+    {
+        self.push_back_shared(list, F::new(element))
+    }
+
+    pub fn push_back_shared(
+        &mut self,
+        list: PoolListId,
+        element: F::Pointer<T>,
+    ) -> Option<NodeHandle> {
+        let list_index = self.resolve_list(list)?;
+        let new_index = self.allocate_entry(element);
+        self.link_back(list_index, new_index);
+        Some(self.handle_of(new_index))
+    }
+
+    pub fn push_front(&mut self, list: PoolListId, element: T) -> Option<NodeHandle>
+    where
+        T: Sized,
+    {
+        self.push_front_shared(list, F::new(element))
+    }
+
+    pub fn push_front_shared(
+        &mut self,
+        list: PoolListId,
+        element: F::Pointer<T>,
+    ) -> Option<NodeHandle> {
+        let list_index = self.resolve_list(list)?;
+        let new_index = self.allocate_entry(element);
+        self.link_front(list_index, new_index);
+        Some(self.handle_of(new_index))
+    }
+
+    pub fn pop_front(&mut self, list: PoolListId) -> Option<F::Pointer<T>> {
+        let head_index = self.front_slot(list)?;
+        self.unlink(head_index);
+        Some(self.vacate(head_index))
+    }
+
+    pub fn pop_back(&mut self, list: PoolListId) -> Option<F::Pointer<T>> {
+        let tail_index = self.back_slot(list)?;
+        self.unlink(tail_index);
+        Some(self.vacate(tail_index))
+    }
+
+    pub fn front(&self, list: PoolListId) -> Option<&T> {
+        self.front_slot(list).map(|slot| self.data(slot))
+    }
+
+    pub fn back(&self, list: PoolListId) -> Option<&T> {
+        self.back_slot(list).map(|slot| self.data(slot))
+    }
+
+    pub fn front_node(&self, list: PoolListId) -> Option<NodeHandle> {
+        self.front_slot(list).map(|slot| self.handle_of(slot))
+    }
+
+    pub fn back_node(&self, list: PoolListId) -> Option<NodeHandle> {
+        self.back_slot(list).map(|slot| self.handle_of(slot))
+    }
+
+    /// The node following the node of the handle in its list.
+    pub fn next_node(&self, node: NodeHandle) -> Option<NodeHandle> {
+        let slot_index = self.resolve_node(node)?;
+        let list_index = self.slot_lists[slot_index];
+        match slot_index == self.list_heads[list_index].tail_index {
+            true => None,
+            false => Some(self.handle_of(self.all_elements[slot_index].next_index)),
+        }
+    }
+
+    pub fn get(&self, node: NodeHandle) -> Option<&T> {
+        self.resolve_node(node).map(|slot| self.data(slot))
+    }
+
+    /// Returns a mutable reference if the pool holds the only pointer to the element.
+    pub fn get_mut(&mut self, node: NodeHandle) -> Option<&mut T> {
+        let slot_index = self.resolve_node(node)?;
+        F::get_mut(self.all_elements[slot_index].data_mut())
+    }
+
+    /// The list the node currently belongs to.
+    pub fn list_of(&self, node: NodeHandle) -> Option<PoolListId> {
+        let slot_index = self.resolve_node(node)?;
+        let list_index = self.slot_lists[slot_index];
+        Some(PoolListId {
+            list_index,
+            generation: self.list_heads[list_index].generation,
+        })
+    }
+
+    pub fn remove(&mut self, node: NodeHandle) -> Option<F::Pointer<T>> {
+        let slot_index = self.resolve_node(node)?;
+        self.unlink(slot_index);
+        Some(self.vacate(slot_index))
+    }
+
+    /// Moves the node to the end of the target list in O(1), `false` if the
+    /// node or the list is stale. The handle of the node stays valid.
+    pub fn move_to_back(&mut self, node: NodeHandle, target: PoolListId) -> bool {
+        match (self.resolve_node(node), self.resolve_list(target)) {
+            (Some(slot_index), Some(list_index)) => {
+                self.unlink(slot_index);
+                self.link_back(list_index, slot_index);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Moves the node to the start of the target list in O(1), `false` if the
+    /// node or the list is stale. The handle of the node stays valid.
+    pub fn move_to_front(&mut self, node: NodeHandle, target: PoolListId) -> bool {
+        match (self.resolve_node(node), self.resolve_list(target)) {
+            (Some(slot_index), Some(list_index)) => {
+                self.unlink(slot_index);
+                self.link_front(list_index, slot_index);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Iterates over the elements of the list, empty if the id is stale.
+    pub fn iter(&self, list: PoolListId) -> PoolIter<'_, T, F> {
+        PoolIter {
+            next_index: self.front_slot(list).unwrap_or(0),
+            remaining: self.len(list).unwrap_or(0),
+            referenced_pool: self,
+        }
+    }
+
+    fn resolve_list(&self, list: PoolListId) -> Option<usize> {
+        self.contains_list(list).then_some(list.list_index)
+    }
+
+    fn resolve_node(&self, node: NodeHandle) -> Option<usize> {
+        arena::resolve_slot(&self.all_elements, node)
+    }
+
+    fn handle_of(&self, slot_index: usize) -> NodeHandle {
+        arena::handle_of(&self.all_elements, slot_index)
+    }
+
+    fn front_slot(&self, list: PoolListId) -> Option<usize> {
+        let list_head = &self.list_heads[self.resolve_list(list)?];
+        (list_head.len > 0).then_some(list_head.head_index)
+    }
+
+    fn back_slot(&self, list: PoolListId) -> Option<usize> {
+        let list_head = &self.list_heads[self.resolve_list(list)?];
+        (list_head.len > 0).then_some(list_head.tail_index)
+    }
+
+    // Only slots of handles that resolved or that are linked into a list are accessed
+    fn data(&self, slot_index: usize) -> &T {
+        self.all_elements[slot_index].data()
+    }
+
+    /// Stores the element in a free slot (or a new one), linking it into a
+    /// list is left to the caller.
+    fn allocate_entry(&mut self, element: F::Pointer<T>) -> usize {
+        let slot_index = arena::occupy_slot(
+            &mut self.all_elements,
+            &mut self.free_indices,
+            &mut self.next_generation,
+            element,
+        );
+        if slot_index == self.slot_lists.len() {
+            self.slot_lists.push(0);
+        }
+        slot_index
+    }
+
+    fn link_back(&mut self, list_index: usize, slot_index: usize) {
+        let anchor_index = self.list_heads[list_index].tail_index;
+        match self.list_heads[list_index].len {
+            0 => self.link_only(list_index, slot_index),
+            _ => arena::link_after(
+                &mut self.all_elements,
+                &mut self.list_heads[list_index].tail_index,
+                anchor_index,
+                slot_index,
+            ),
+        }
+        self.list_heads[list_index].len += 1;
+        self.slot_lists[slot_index] = list_index;
+    }
+
+    fn link_front(&mut self, list_index: usize, slot_index: usize) {
+        let anchor_index = self.list_heads[list_index].head_index;
+        match self.list_heads[list_index].len {
+            0 => self.link_only(list_index, slot_index),
+            _ => arena::link_before(
+                &mut self.all_elements,
+                &mut self.list_heads[list_index].head_index,
+                anchor_index,
+                slot_index,
+            ),
+        }
+        self.list_heads[list_index].len += 1;
+        self.slot_lists[slot_index] = list_index;
+    }
+
+    /// Makes the slot the only node of the empty list.
+    fn link_only(&mut self, list_index: usize, slot_index: usize) {
+        let list_head = &mut self.list_heads[list_index];
+        list_head.head_index = slot_index;
+        list_head.tail_index = slot_index;
+        let entry = &mut self.all_elements[slot_index];
+        entry.next_index = slot_index;
+        entry.prev_index = slot_index;
+    }
+
+    /// Detaches the slot from its list, the element stays in the slot.
+    fn unlink(&mut self, slot_index: usize) {
+        let list_head = &mut self.list_heads[self.slot_lists[slot_index]];
+        list_head.len -= 1;
+        arena::unlink_slot(
+            &mut self.all_elements,
+            &mut list_head.head_index,
+            &mut list_head.tail_index,
+            slot_index,
+        );
+    }
+
+    /// Takes the element out of an unlinked slot, handles to it become stale.
+    fn vacate(&mut self, slot_index: usize) -> F::Pointer<T> {
+        arena::vacate_slot(
+            &mut self.all_elements,
+            &mut self.free_indices,
+            &mut self.next_generation,
+            slot_index,
+        )
+    }
+}
+
+/// Borrowing iterator over one list of a [`ListPool`], created by [`ListPool::iter`].
+pub struct PoolIter<'a, T: ?Sized, F: PointerFamily = RcFamily> {
+    next_index: usize,
+    remaining: usize,
+    referenced_pool: &'a ListPool<T, F>,
+}
+
+impl<'a, T: ?Sized, F: PointerFamily> Iterator for PoolIter<'a, T, F> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let found_index = self.next_index;
+        self.next_index = self.referenced_pool.all_elements[found_index].next_index;
+        self.remaining -= 1;
+
+        Some(self.referenced_pool.data(found_index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: ?Sized, F: PointerFamily> ExactSizeIterator for PoolIter<'a, T, F> {}
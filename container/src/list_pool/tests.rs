@@ -0,0 +1,76 @@
+use std::rc::Rc;
+
+use super::*;
+
+#[test]
+fn tc0_pool_lists() {
+    let mut pool = ListPool::new();
+    let ready = pool.create_list();
+    let blocked = pool.create_list();
+
+    let task_a = pool.push_back(ready, 'a').unwrap();
+    let task_b = pool.push_back(ready, 'b').unwrap();
+    pool.push_front(ready, 'c');
+    pool.push_back(blocked, 'd');
+    assert_eq!(pool.iter(ready).collect::<String>(), "cab");
+    assert_eq!(pool.len(ready), Some(3));
+    assert_eq!(pool.node_count(), 4);
+
+    assert!(pool.move_to_back(task_a, blocked));
+    assert!(pool.move_to_front(task_b, blocked));
+    assert_eq!(pool.iter(ready).collect::<String>(), "c");
+    assert_eq!(pool.iter(blocked).collect::<String>(), "bda");
+    assert_eq!(pool.list_of(task_a), Some(blocked));
+    assert_eq!(pool.get(task_a), Some(&'a'));
+    assert_eq!(pool.back(blocked), Some(&'a'));
+    assert_eq!(
+        pool.next_node(task_b).and_then(|node| pool.get(node)),
+        Some(&'d')
+    );
+    assert_eq!(pool.next_node(task_a), None);
+
+    // moving inside of the same list
+    assert!(pool.move_to_back(task_b, blocked));
+    assert_eq!(pool.iter(blocked).collect::<String>(), "dab");
+    *pool.get_mut(task_b).unwrap() = 'e';
+    assert_eq!(pool.iter(blocked).collect::<String>(), "dae");
+
+    assert_eq!(*pool.remove(task_a).unwrap(), 'a');
+    assert_eq!(pool.get(task_a), None);
+    assert!(!pool.move_to_back(task_a, ready));
+    let task_f = pool.push_back(ready, 'f').unwrap();
+    assert_eq!(task_f.slot(), task_a.slot());
+    assert_eq!(pool.get(task_a), None);
+
+    assert_eq!(pool.pop_front(blocked).as_deref(), Some(&'d'));
+    assert_eq!(pool.pop_back(blocked).as_deref(), Some(&'e'));
+    assert_eq!(pool.is_empty(blocked), Some(true));
+    assert_eq!(pool.back_node(ready), Some(task_f));
+}
+
+#[test]
+fn tc1_release_lists() {
+    let shared_element = Rc::new(1);
+    let mut pool: ListPool<i32> = ListPool::with_capacity(4);
+    let first = pool.create_list();
+    pool.push_back_shared(first, Rc::clone(&shared_element));
+    let moved = pool.push_back(first, 2).unwrap();
+
+    let second = pool.create_list();
+    pool.move_to_back(moved, second);
+    assert!(pool.release_list(first));
+    assert!(!pool.release_list(first));
+    assert_eq!(Rc::strong_count(&shared_element), 1);
+    assert_eq!(pool.len(first), None);
+    assert_eq!(pool.push_back(first, 3), None);
+    assert_eq!(pool.iter(first).count(), 0);
+
+    let third = pool.create_list();
+    assert!(!pool.contains_list(first));
+    assert!(pool.contains_list(third));
+    assert!(!pool.move_to_back(moved, first));
+    assert!(pool.move_to_back(moved, third));
+    assert_eq!(pool.iter(third).copied().collect::<Vec<_>>(), [2]);
+    assert_eq!(pool.len(second), Some(0));
+    assert_eq!(pool.node_count(), 1);
+}
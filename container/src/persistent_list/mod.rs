@@ -0,0 +1,257 @@
+use std::fmt;
+
+use crate::pointer_family::{RcFamily, SharedPointerFamily};
+
+#[cfg(test)]
+mod tests;
+
+/// An immutable singly linked list whose versions share their tails.
+///
+/// Every operation leaves the list untouched and returns a new version.
+/// [`push_front`](PersistentList::push_front) and
+/// [`tail`](PersistentList::tail) take constant time and share the whole
+/// list, [`update_at`](PersistentList::update_at) and
+/// [`concat`](PersistentList::concat) only copy the nodes in front of the
+/// change. Copied nodes still share their elements, no element is cloned.
+///
+/// ```
+/// use container::PersistentList;
+///
+/// let base = PersistentList::new().push_front(3).push_front(2).push_front(1);
+/// let updated = base.update_at(1, 20).unwrap();
+/// let extended = base.tail().unwrap().push_front(0);
+///
+/// assert_eq!(base.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+/// assert_eq!(updated.iter().copied().collect::<Vec<_>>(), [1, 20, 3]);
+/// assert_eq!(extended.iter().copied().collect::<Vec<_>>(), [0, 2, 3]);
+/// ```
+pub struct PersistentList<T: ?Sized, F: SharedPointerFamily = RcFamily> {
+    head: Option<F::Pointer<PersistentNode<T, F>>>,
+    len: usize,
+}
+
+struct PersistentNode<T: ?Sized, F: SharedPointerFamily> {
+    element: F::Pointer<T>,
+    next: Option<F::Pointer<PersistentNode<T, F>>>,
+}
+
+impl<T: ?Sized, F: SharedPointerFamily> Default for PersistentList<T, F> {
+    fn default() -> Self {
+        PersistentList { head: None, len: 0 }
+    }
+}
+
+impl<T: ?Sized> PersistentList<T> {
+    pub fn new() -> PersistentList<T> {
+        PersistentList::default()
+    }
+}
+
+impl<T: ?Sized, F: SharedPointerFamily> PersistentList<T, F> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.head.as_ref().map(|head_node| &*head_node.element)
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
+
+    /// Returns a new version with the element in front, sharing all of this list.
+    pub fn push_front(&self, element: T) -> PersistentList<T, F>
+    where
+        T: Sized,
+    {
+        self.push_front_shared(F::new(element))
+    }
+
+    pub fn push_front_shared(&self, element: F::Pointer<T>) -> PersistentList<T, F> {
+        PersistentList {
+            head: Some(F::new(PersistentNode {
+                element,
+                next: self.head.as_ref().map(F::clone_pointer),
+            })),
+            len: self.len + 1,
+        }
+    }
+
+    /// Returns the list without its first element, `None` if it is empty.
+    pub fn tail(&self) -> Option<PersistentList<T, F>> {
+        self.head.as_ref().map(|head_node| PersistentList {
+            head: head_node.next.as_ref().map(F::clone_pointer),
+            len: self.len - 1,
+        })
+    }
+
+    /// Returns a new version with the element at the index replaced, `None` if
+    /// the index is out of range. The nodes behind the index are shared.
+    pub fn update_at(&self, index: usize, element: T) -> Option<PersistentList<T, F>>
+    where
+        T: Sized,
+    {
+        self.update_at_shared(index, F::new(element))
+    }
+
+    pub fn update_at_shared(
+        &self,
+        index: usize,
+        element: F::Pointer<T>,
+    ) -> Option<PersistentList<T, F>> {
+        if index >= self.len {
+            return None;
+        }
+
+        let mut node_iter = self.nodes();
+        let copied_prefix: Vec<&PersistentNode<T, F>> = node_iter.by_ref().take(index).collect();
+        let replaced_node = node_iter.next().expect("index is checked above");
+        let updated_head = F::new(PersistentNode {
+            element,
+            next: replaced_node.next.as_ref().map(F::clone_pointer),
+        });
+
+        Some(PersistentList {
+            head: Some(Self::prepend_copies(copied_prefix, Some(updated_head))),
+            len: self.len,
+        })
+    }
+
+    /// Returns this list followed by `other`. This list is copied, `other` is
+    /// shared completely.
+    pub fn concat(&self, other: &PersistentList<T, F>) -> PersistentList<T, F> {
+        let other_head = other.head.as_ref().map(F::clone_pointer);
+        let head = match self.is_empty() {
+            true => other_head,
+            false => Some(Self::prepend_copies(self.nodes().collect(), other_head)),
+        };
+
+        PersistentList {
+            head,
+            len: self.len + other.len,
+        }
+    }
+
+    pub fn iter(&self) -> PersistentIter<'_, T, F> {
+        PersistentIter {
+            next_node: self.head.as_deref(),
+            remaining: self.len,
+        }
+    }
+
+    /// `true` if both versions share their first node and thus all elements.
+    pub fn ptr_eq(&self, other: &PersistentList<T, F>) -> bool {
+        match (&self.head, &other.head) {
+            (Some(own_head), Some(other_head)) => std::ptr::eq(&**own_head, &**other_head),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    fn nodes(&self) -> impl Iterator<Item = &PersistentNode<T, F>> {
+        std::iter::successors(self.head.as_deref(), |node| node.next.as_deref())
+    }
+
+    /// Puts copies of the nodes in front of `rest`, keeping their order.
+    fn prepend_copies(
+        copied_nodes: Vec<&PersistentNode<T, F>>,
+        rest: Option<F::Pointer<PersistentNode<T, F>>>,
+    ) -> F::Pointer<PersistentNode<T, F>> {
+        let mut head = rest;
+        for copied_node in copied_nodes.into_iter().rev() {
+            head = Some(F::new(PersistentNode {
+                element: F::clone_pointer(&copied_node.element),
+                next: head,
+            }));
+        }
+        head.expect("at least one node is copied or given")
+    }
+}
+
+// Dropping the nodes one after the other, the default recursive drop would
+// overflow the stack for long lists.
+impl<T: ?Sized, F: SharedPointerFamily> Drop for PersistentList<T, F> {
+    fn drop(&mut self) {
+        let mut next_node = self.head.take();
+        while let Some(node) = next_node {
+            next_node = match F::try_unwrap(node) {
+                Ok(mut unique_node) => unique_node.next.take(),
+                // the rest is shared with another version
+                Err(_) => None,
+            };
+        }
+    }
+}
+
+// Cloning a version is O(1), both share all nodes.
+impl<T: ?Sized, F: SharedPointerFamily> Clone for PersistentList<T, F> {
+    fn clone(&self) -> Self {
+        PersistentList {
+            head: self.head.as_ref().map(F::clone_pointer),
+            len: self.len,
+        }
+    }
+}
+
+impl<T: ?Sized + fmt::Debug, F: SharedPointerFamily> fmt::Debug for PersistentList<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: ?Sized + PartialEq, F: SharedPointerFamily> PartialEq for PersistentList<T, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: ?Sized + Eq, F: SharedPointerFamily> Eq for PersistentList<T, F> {}
+
+impl<T, F: SharedPointerFamily> FromIterator<T> for PersistentList<T, F> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let elements: Vec<T> = iter.into_iter().collect();
+        elements
+            .into_iter()
+            .rev()
+            .fold(PersistentList::default(), |list, element| {
+                list.push_front(element)
+            })
+    }
+}
+
+impl<'a, T: ?Sized, F: SharedPointerFamily> IntoIterator for &'a PersistentList<T, F> {
+    type Item = &'a T;
+    type IntoIter = PersistentIter<'a, T, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Borrowing iterator over a [`PersistentList`], created by [`PersistentList::iter`].
+pub struct PersistentIter<'a, T: ?Sized, F: SharedPointerFamily = RcFamily> {
+    next_node: Option<&'a PersistentNode<T, F>>,
+    remaining: usize,
+}
+
+impl<'a, T: ?Sized, F: SharedPointerFamily> Iterator for PersistentIter<'a, T, F> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let found_node = self.next_node?;
+        self.next_node = found_node.next.as_deref();
+        self.remaining -= 1;
+        Some(&*found_node.element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: ?Sized, F: SharedPointerFamily> ExactSizeIterator for PersistentIter<'a, T, F> {}
@@ -0,0 +1,75 @@
+use std::rc::Rc;
+
+use super::*;
+use crate::ArcFamily;
+
+#[test]
+fn tc0_structural_sharing() {
+    let base: PersistentList<i32> = (1..=4).collect();
+    assert_eq!(base.len(), 4);
+    assert_eq!(base.front(), Some(&1));
+    assert_eq!(base.get(3), Some(&4));
+    assert_eq!(base.get(4), None);
+
+    let pushed = base.push_front(0);
+    assert!(pushed.tail().unwrap().ptr_eq(&base));
+    assert_eq!(format!("{pushed:?}"), "[0, 1, 2, 3, 4]");
+
+    let updated = base.update_at(2, 30).unwrap();
+    assert_eq!(updated, [1, 2, 30, 4].into_iter().collect());
+    assert_eq!(base, (1..=4).collect());
+    // the node behind the update is shared, the ones in front are copies
+    let base_rest = base.tail().unwrap().tail().unwrap().tail().unwrap();
+    let updated_rest = updated.tail().unwrap().tail().unwrap().tail().unwrap();
+    assert!(base_rest.ptr_eq(&updated_rest));
+    assert!(!base.tail().unwrap().ptr_eq(&updated.tail().unwrap()));
+    assert!(base.update_at(4, 0).is_none());
+
+    let concatenated = base.concat(&updated);
+    assert_eq!(concatenated.len(), 8);
+    assert_eq!(
+        concatenated.iter().copied().collect::<Vec<_>>(),
+        [1, 2, 3, 4, 1, 2, 30, 4]
+    );
+    let mut shared_rest = concatenated.clone();
+    for _ in 0..4 {
+        shared_rest = shared_rest.tail().unwrap();
+    }
+    assert!(shared_rest.ptr_eq(&updated));
+    assert!(PersistentList::new().concat(&base).ptr_eq(&base));
+
+    let empty: PersistentList<i32> = PersistentList::new();
+    assert!(empty.tail().is_none());
+    assert!(empty.front().is_none());
+    assert_eq!(empty.iter().len(), 0);
+}
+
+#[test]
+fn tc1_shared_elements() {
+    let shared_element = Rc::new(String::from("shared"));
+    let first = PersistentList::new().push_front_shared(Rc::clone(&shared_element));
+    let second = first.push_front(String::from("front")).concat(&first);
+    assert_eq!(Rc::strong_count(&shared_element), 3);
+    // nothing in front of the first element needs to be copied
+    let updated = second.update_at(0, String::from("new front")).unwrap();
+    assert_eq!(Rc::strong_count(&shared_element), 3);
+
+    drop(first);
+    drop(second);
+    assert_eq!(updated.get(1).map(String::as_str), Some("shared"));
+    assert_eq!(Rc::strong_count(&shared_element), 3);
+    drop(updated);
+    assert_eq!(Rc::strong_count(&shared_element), 1);
+
+    let sync_list: PersistentList<i32, ArcFamily> = (0..3).collect();
+    let handle = std::thread::spawn(move || sync_list.push_front(-1).len());
+    assert_eq!(handle.join().unwrap(), 4);
+}
+
+#[test]
+fn tc2_drop_long_list() {
+    let long_list: PersistentList<usize> = (0..200_000).collect();
+    let shorter = long_list.tail().unwrap();
+    drop(long_list);
+    assert_eq!(shorter.len(), 199_999);
+}
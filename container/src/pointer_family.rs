@@ -0,0 +1,115 @@
+use std::ops::Deref;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Selects the pointer type a container stores its elements in.
+///
+/// [`List`](crate::List) uses [`RcFamily`] by default. [`ArcFamily`] makes the
+/// container `Send` and `Sync` (as long as `T` is), [`BoxFamily`] stores
+/// uniquely owned elements without any reference counting.
+pub trait PointerFamily {
+    type Pointer<T: ?Sized>: Deref<Target = T>;
+
+    fn new<T>(value: T) -> Self::Pointer<T>;
+
+    /// Returns a mutable reference if the pointer is the only owner of the value.
+    fn get_mut<T: ?Sized>(pointer: &mut Self::Pointer<T>) -> Option<&mut T>;
+
+    /// Returns a mutable reference, cloning the value first if it is shared.
+    fn make_mut<T: Clone>(pointer: &mut Self::Pointer<T>) -> &mut T;
+
+    /// Moves the value out of the pointer if it is the only owner of the value.
+    fn try_unwrap<T>(pointer: Self::Pointer<T>) -> Result<T, Self::Pointer<T>>;
+
+    /// Moves the value out of the pointer, cloning it if it is shared.
+    fn unwrap_or_clone<T: Clone>(pointer: Self::Pointer<T>) -> T {
+        Self::try_unwrap(pointer).unwrap_or_else(|shared_pointer| (*shared_pointer).clone())
+    }
+}
+
+/// Pointer families whose pointers can be cloned without cloning the value,
+/// which allows handing out additional owners of an element.
+pub trait SharedPointerFamily: PointerFamily {
+    fn clone_pointer<T: ?Sized>(pointer: &Self::Pointer<T>) -> Self::Pointer<T>;
+}
+
+/// Elements are stored as [`Rc`], the containers are neither `Send` nor `Sync`.
+pub struct RcFamily;
+
+/// Elements are stored as [`Arc`], the containers can be shared between threads.
+pub struct ArcFamily;
+
+/// Elements are stored as [`Box`] and are owned by the container alone.
+pub struct BoxFamily;
+
+impl PointerFamily for RcFamily {
+    type Pointer<T: ?Sized> = Rc<T>;
+
+    fn new<T>(value: T) -> Rc<T> {
+        Rc::new(value)
+    }
+
+    fn get_mut<T: ?Sized>(pointer: &mut Rc<T>) -> Option<&mut T> {
+        Rc::get_mut(pointer)
+    }
+
+    fn make_mut<T: Clone>(pointer: &mut Rc<T>) -> &mut T {
+        Rc::make_mut(pointer)
+    }
+
+    fn try_unwrap<T>(pointer: Rc<T>) -> Result<T, Rc<T>> {
+        Rc::try_unwrap(pointer)
+    }
+}
+
+impl SharedPointerFamily for RcFamily {
+    fn clone_pointer<T: ?Sized>(pointer: &Rc<T>) -> Rc<T> {
+        Rc::clone(pointer)
+    }
+}
+
+impl PointerFamily for ArcFamily {
+    type Pointer<T: ?Sized> = Arc<T>;
+
+    fn new<T>(value: T) -> Arc<T> {
+        Arc::new(value)
+    }
+
+    fn get_mut<T: ?Sized>(pointer: &mut Arc<T>) -> Option<&mut T> {
+        Arc::get_mut(pointer)
+    }
+
+    fn make_mut<T: Clone>(pointer: &mut Arc<T>) -> &mut T {
+        Arc::make_mut(pointer)
+    }
+
+    fn try_unwrap<T>(pointer: Arc<T>) -> Result<T, Arc<T>> {
+        Arc::try_unwrap(pointer)
+    }
+}
+
+impl SharedPointerFamily for ArcFamily {
+    fn clone_pointer<T: ?Sized>(pointer: &Arc<T>) -> Arc<T> {
+        Arc::clone(pointer)
+    }
+}
+
+impl PointerFamily for BoxFamily {
+    type Pointer<T: ?Sized> = Box<T>;
+
+    fn new<T>(value: T) -> Box<T> {
+        Box::new(value)
+    }
+
+    fn get_mut<T: ?Sized>(pointer: &mut Box<T>) -> Option<&mut T> {
+        Some(pointer)
+    }
+
+    fn make_mut<T: Clone>(pointer: &mut Box<T>) -> &mut T {
+        pointer
+    }
+
+    fn try_unwrap<T>(pointer: Box<T>) -> Result<T, Box<T>> {
+        Ok(*pointer)
+    }
+}
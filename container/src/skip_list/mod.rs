@@ -0,0 +1,553 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Index;
+
+use crate::list::ListError;
+use crate::pointer_family::{PointerFamily, RcFamily, SharedPointerFamily};
+
+#[cfg(test)]
+mod tests;
+
+// Lanes above this level would only pay off beyond 2^32 elements.
+const MAX_LEVEL: usize = 32;
+
+/// A sequence with O(log n) expected positional access, built as an indexable
+/// skip list.
+///
+/// Every node takes part in a random number of lanes, each lane link knows
+/// how many elements it skips. `get`, `insert_before` and `remove_at` follow
+/// the express lanes instead of walking every element like
+/// [`List`](crate::List) does. The elements are held by the pointer family
+/// `F` in the same way as in a `List`.
+///
+/// Inserting only through [`insert_by_key`](SkipList::insert_by_key) keeps
+/// the list ordered, it can then be used as an ordered map with
+/// [`find_by_key`](SkipList::find_by_key) and
+/// [`remove_by_key`](SkipList::remove_by_key).
+///
+/// ```
+/// use container::SkipList;
+///
+/// let mut skip_list: SkipList<u32> = (0..1000).collect();
+/// skip_list.insert_before(500, 5000);
+/// skip_list.remove_at(0);
+///
+/// assert_eq!(skip_list[499], 5000);
+/// assert_eq!(skip_list.len(), 1000);
+/// ```
+pub struct SkipList<T: ?Sized, F: PointerFamily = RcFamily> {
+    // slot 0 is the head sentinel without element, it has a lane on every level
+    all_nodes: Vec<SkipNode<F::Pointer<T>>>,
+    free_indices: Vec<usize>,
+    len: usize,
+    level_seed: u64,
+}
+
+#[derive(Clone)]
+struct SkipNode<P> {
+    element: Option<P>,
+    lanes: Vec<SkipLane>,
+}
+
+// The span is the distance in elements to the next node of the lane, it is
+// only maintained while there is a next node.
+#[derive(Clone, Copy)]
+struct SkipLane {
+    next_index: Option<usize>,
+    span: usize,
+}
+
+impl<P> SkipNode<P> {
+    fn data(&self) -> &P {
+        self.element
+            .as_ref()
+            .expect("only the head sentinel has no element")
+    }
+
+    fn data_mut(&mut self) -> &mut P {
+        self.element
+            .as_mut()
+            .expect("only the head sentinel has no element")
+    }
+}
+
+impl<T: ?Sized, F: PointerFamily> Default for SkipList<T, F> {
+    fn default() -> Self {
+        SkipList {
+            all_nodes: vec![SkipNode {
+                element: None,
+                lanes: vec![SkipLane {
+                    next_index: None,
+                    span: 0,
+                }],
+            }],
+            free_indices: Vec::new(),
+            len: 0,
+            level_seed: 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+}
+
+impl<T: ?Sized> SkipList<T> {
+    pub fn new() -> SkipList<T> {
+        SkipList::default()
+    }
+}
+
+impl<T: ?Sized, F: PointerFamily> SkipList<T, F> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn append(&mut self, element: T)
+    where
+        T: Sized,
+    {
+        self.append_shared(F::new(element));
+    }
+
+    pub fn append_shared(&mut self, element: F::Pointer<T>) {
+        self.link_at(self.len, element);
+    }
+
+    pub fn push_front(&mut self, element: T)
+    where
+        T: Sized,
+    {
+        self.push_front_shared(F::new(element));
+    }
+
+    pub fn push_front_shared(&mut self, element: F::Pointer<T>) {
+        self.link_at(0, element);
+    }
+
+    pub fn pop_front(&mut self) -> Option<F::Pointer<T>> {
+        self.remove_at(0)
+    }
+
+    pub fn pop_back(&mut self) -> Option<F::Pointer<T>> {
+        self.remove_at(self.len.checked_sub(1)?)
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len.checked_sub(1)?)
+    }
+
+    pub fn at(&self, index: usize) -> Option<F::Pointer<T>>
+    where
+        F: SharedPointerFamily,
+    {
+        self.slot_at(index)
+            .map(|slot| F::clone_pointer(self.all_nodes[slot].data()))
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.slot_at(index)
+            .map(|slot| &**self.all_nodes[slot].data())
+    }
+
+    /// Returns a mutable reference to the element if the list holds the only
+    /// pointer to it, `None` if the element is shared or out of range.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.slot_at(index)
+            .and_then(|slot| F::get_mut(self.all_nodes[slot].data_mut()))
+    }
+
+    /// Returns a mutable reference to the element, cloning it first if it is
+    /// shared with another pointer (copy-on-write).
+    pub fn make_mut(&mut self, index: usize) -> Option<&mut T>
+    where
+        T: Clone,
+    {
+        self.slot_at(index)
+            .map(|slot| F::make_mut(self.all_nodes[slot].data_mut()))
+    }
+
+    /// Inserts the element in front of the element at the index, an index equal
+    /// to the length appends it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is greater than the length.
+    pub fn insert_before(&mut self, insert_index: usize, element: T)
+    where
+        T: Sized,
+    {
+        self.insert_before_shared(insert_index, F::new(element));
+    }
+
+    pub fn insert_before_shared(&mut self, insert_index: usize, element: F::Pointer<T>) {
+        if insert_index > self.len {
+            panic!(
+                "{}",
+                ListError::OutOfRange {
+                    index: insert_index,
+                    len: self.len
+                }
+            );
+        }
+        self.link_at(insert_index, element);
+    }
+
+    /// Removes the element at the index, `None` if the index is out of range.
+    pub fn remove_at(&mut self, index: usize) -> Option<F::Pointer<T>> {
+        if index >= self.len {
+            return None;
+        }
+
+        let height = self.height();
+        let predecessors = self.predecessors(index);
+        let (first_predecessor, _) = predecessors[0];
+        let removed_slot = self.all_nodes[first_predecessor].lanes[0]
+            .next_index
+            .expect("the index is checked above");
+
+        for (level, &(predecessor, _)) in predecessors[..height].iter().enumerate() {
+            let predecessor_lane = self.all_nodes[predecessor].lanes[level];
+            if predecessor_lane.next_index == Some(removed_slot) {
+                // the lane bridges the removed node
+                let removed_lane = self.all_nodes[removed_slot].lanes[level];
+                self.all_nodes[predecessor].lanes[level] = SkipLane {
+                    next_index: removed_lane.next_index,
+                    span: predecessor_lane.span + removed_lane.span - 1,
+                };
+            } else if predecessor_lane.next_index.is_some() {
+                self.all_nodes[predecessor].lanes[level].span -= 1;
+            }
+        }
+
+        // lanes left empty at the top are dropped
+        let head_lanes = &mut self.all_nodes[0].lanes;
+        while head_lanes.len() > 1
+            && head_lanes
+                .last()
+                .is_some_and(|lane| lane.next_index.is_none())
+        {
+            head_lanes.pop();
+        }
+
+        self.len -= 1;
+        self.free_indices.push(removed_slot);
+        let removed_node = &mut self.all_nodes[removed_slot];
+        removed_node.lanes.clear();
+        removed_node.element.take()
+    }
+
+    /// Inserts the element behind all elements with a smaller or equal key and
+    /// returns its index.
+    ///
+    /// The list has to be ordered by the same key, which holds as long as
+    /// elements are only inserted by key.
+    pub fn insert_by_key<K, KF>(&mut self, element: T, key_fn: KF) -> usize
+    where
+        T: Sized,
+        K: Ord,
+        KF: Fn(&T) -> K,
+    {
+        self.insert_by_key_shared(F::new(element), key_fn)
+    }
+
+    pub fn insert_by_key_shared<K, KF>(&mut self, element: F::Pointer<T>, key_fn: KF) -> usize
+    where
+        K: Ord,
+        KF: Fn(&T) -> K,
+    {
+        let new_key = key_fn(&element);
+        let (_, insert_index) = self.seek(|existing_element| key_fn(existing_element) <= new_key);
+        self.link_at(insert_index, element);
+        insert_index
+    }
+
+    /// Index of the first element with the key, the list has to be ordered by it.
+    pub fn position_by_key<K, KF>(&self, key: &K, key_fn: KF) -> Option<usize>
+    where
+        K: Ord,
+        KF: Fn(&T) -> K,
+    {
+        let (last_smaller, index) = self.seek(|existing_element| key_fn(existing_element) < *key);
+        let candidate = self.all_nodes[last_smaller].lanes[0].next_index?;
+        (key_fn(self.all_nodes[candidate].data()) == *key).then_some(index)
+    }
+
+    pub fn find_by_key<K, KF>(&self, key: &K, key_fn: KF) -> Option<&T>
+    where
+        K: Ord,
+        KF: Fn(&T) -> K,
+    {
+        self.position_by_key(key, key_fn)
+            .and_then(|index| self.get(index))
+    }
+
+    /// Removes the first element with the key, the list has to be ordered by it.
+    pub fn remove_by_key<K, KF>(&mut self, key: &K, key_fn: KF) -> Option<F::Pointer<T>>
+    where
+        K: Ord,
+        KF: Fn(&T) -> K,
+    {
+        let index = self.position_by_key(key, key_fn)?;
+        self.remove_at(index)
+    }
+
+    pub fn iter(&self) -> SkipIter<'_, T, F> {
+        SkipIter {
+            next_slot: self.all_nodes[0].lanes[0].next_index,
+            remaining: self.len,
+            referenced_entry: self,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        let level_seed = self.level_seed;
+        *self = SkipList::default();
+        self.level_seed = level_seed;
+    }
+
+    /// Slot of the element at the index, following the lanes from the top.
+    fn slot_at(&self, index: usize) -> Option<usize> {
+        if index >= self.len {
+            return None;
+        }
+
+        let (predecessor, _) = (0..self.height())
+            .rev()
+            .fold((0, 0), |current, level| self.advance(current, level, index));
+        self.all_nodes[predecessor].lanes[0].next_index
+    }
+
+    /// The last node in front of the index on every level with its rank, bottom
+    /// level first. The head has rank 0 and the element at index `i` rank `i + 1`.
+    /// Only the first `height()` entries are set, the buffer spares an
+    /// allocation on every insertion and removal.
+    fn predecessors(&self, index: usize) -> [(usize, usize); MAX_LEVEL] {
+        let mut predecessors = [(0, 0); MAX_LEVEL];
+        let mut current = (0, 0);
+        for level in (0..self.height()).rev() {
+            current = self.advance(current, level, index);
+            predecessors[level] = current;
+        }
+        predecessors
+    }
+
+    fn advance(
+        &self,
+        (mut slot, mut rank): (usize, usize),
+        level: usize,
+        index: usize,
+    ) -> (usize, usize) {
+        while let SkipLane {
+            next_index: Some(next_slot),
+            span,
+        } = self.all_nodes[slot].lanes[level]
+        {
+            if rank + span > index {
+                break;
+            }
+            slot = next_slot;
+            rank += span;
+        }
+        (slot, rank)
+    }
+
+    /// Follows the lanes while the next element satisfies the predicate, which
+    /// has to hold for a prefix of the list. Returns the last node passed and
+    /// the length of the prefix.
+    fn seek<P: Fn(&T) -> bool>(&self, in_prefix: P) -> (usize, usize) {
+        let (mut slot, mut rank) = (0, 0);
+        for level in (0..self.height()).rev() {
+            while let SkipLane {
+                next_index: Some(next_slot),
+                span,
+            } = self.all_nodes[slot].lanes[level]
+            {
+                if !in_prefix(self.all_nodes[next_slot].data()) {
+                    break;
+                }
+                slot = next_slot;
+                rank += span;
+            }
+        }
+        (slot, rank)
+    }
+
+    fn height(&self) -> usize {
+        self.all_nodes[0].lanes.len()
+    }
+
+    /// Number of lanes for a new node, every further lane with probability 1/2.
+    fn random_level(&mut self) -> usize {
+        // xorshift64*
+        self.level_seed ^= self.level_seed >> 12;
+        self.level_seed ^= self.level_seed << 25;
+        self.level_seed ^= self.level_seed >> 27;
+        let random_bits = self.level_seed.wrapping_mul(0x2545_F491_4F6C_DD1D);
+        usize::min(random_bits.trailing_ones() as usize + 1, MAX_LEVEL)
+    }
+
+    fn link_at(&mut self, insert_index: usize, element: F::Pointer<T>) {
+        let new_level = self.random_level();
+        while self.height() < new_level {
+            self.all_nodes[0].lanes.push(SkipLane {
+                next_index: None,
+                span: 0,
+            });
+        }
+
+        let height = self.height();
+        let predecessors = self.predecessors(insert_index);
+        let new_lanes = vec![
+            SkipLane {
+                next_index: None,
+                span: 0,
+            };
+            new_level
+        ];
+        let new_slot = match self.free_indices.pop() {
+            Some(free_slot) => {
+                self.all_nodes[free_slot] = SkipNode {
+                    element: Some(element),
+                    lanes: new_lanes,
+                };
+                free_slot
+            }
+            None => {
+                self.all_nodes.push(SkipNode {
+                    element: Some(element),
+                    lanes: new_lanes,
+                });
+                self.all_nodes.len() - 1
+            }
+        };
+
+        for (level, &(predecessor, predecessor_rank)) in predecessors[..height].iter().enumerate() {
+            let predecessor_lane = self.all_nodes[predecessor].lanes[level];
+            if level < new_level {
+                // the new node gets rank insert_index + 1 and splits the lane
+                self.all_nodes[new_slot].lanes[level] = SkipLane {
+                    next_index: predecessor_lane.next_index,
+                    span: match predecessor_lane.next_index {
+                        Some(_) => predecessor_lane.span + predecessor_rank - insert_index,
+                        None => 0,
+                    },
+                };
+                self.all_nodes[predecessor].lanes[level] = SkipLane {
+                    next_index: Some(new_slot),
+                    span: insert_index + 1 - predecessor_rank,
+                };
+            } else if predecessor_lane.next_index.is_some() {
+                self.all_nodes[predecessor].lanes[level].span += 1;
+            }
+        }
+
+        self.len += 1;
+    }
+}
+
+// Cloning copies the pointers, so for Rc and Arc both lists share their elements.
+impl<T: ?Sized, F: PointerFamily> Clone for SkipList<T, F>
+where
+    F::Pointer<T>: Clone,
+{
+    fn clone(&self) -> Self {
+        SkipList {
+            all_nodes: self.all_nodes.clone(),
+            free_indices: self.free_indices.clone(),
+            len: self.len,
+            level_seed: self.level_seed,
+        }
+    }
+}
+
+impl<T: ?Sized + fmt::Debug, F: PointerFamily> fmt::Debug for SkipList<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: ?Sized + PartialEq, F: PointerFamily> PartialEq for SkipList<T, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: ?Sized + Eq, F: PointerFamily> Eq for SkipList<T, F> {}
+
+impl<T: ?Sized, F: PointerFamily> Index<usize> for SkipList<T, F> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).unwrap_or_else(|| {
+            panic!(
+                "{}",
+                ListError::OutOfRange {
+                    index,
+                    len: self.len
+                }
+            )
+        })
+    }
+}
+
+impl<T, F: PointerFamily> FromIterator<T> for SkipList<T, F> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut new_list = SkipList::default();
+        new_list.extend(iter);
+        new_list
+    }
+}
+
+impl<T, F: PointerFamily> Extend<T> for SkipList<T, F> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for element in iter {
+            self.append(element);
+        }
+    }
+}
+
+impl<T, F: PointerFamily, const COUNT: usize> From<[T; COUNT]> for SkipList<T, F> {
+    fn from(given_array: [T; COUNT]) -> Self {
+        given_array.into_iter().collect()
+    }
+}
+
+impl<'a, T: ?Sized, F: PointerFamily> IntoIterator for &'a SkipList<T, F> {
+    type Item = &'a T;
+    type IntoIter = SkipIter<'a, T, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Borrowing iterator over a [`SkipList`], created by [`SkipList::iter`].
+pub struct SkipIter<'a, T: ?Sized, F: PointerFamily = RcFamily> {
+    next_slot: Option<usize>,
+    remaining: usize,
+    referenced_entry: &'a SkipList<T, F>,
+}
+
+impl<'a, T: ?Sized, F: PointerFamily> Iterator for SkipIter<'a, T, F> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let found_node = &self.referenced_entry.all_nodes[self.next_slot?];
+        self.next_slot = found_node.lanes[0].next_index;
+        self.remaining -= 1;
+        Some(&**found_node.data())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: ?Sized, F: PointerFamily> ExactSizeIterator for SkipIter<'a, T, F> {}
+
+impl<'a, T: ?Sized, F: PointerFamily> FusedIterator for SkipIter<'a, T, F> {}
@@ -0,0 +1,113 @@
+use std::rc::Rc;
+
+use super::*;
+use crate::BoxFamily;
+use crate::test_support::{PositionalList, check_random_positional_ops};
+
+#[test]
+fn tc0_positional_access() {
+    let mut skip_list: SkipList<i32> = SkipList::from([1, 2, 3]);
+    assert_eq!(skip_list.len(), 3);
+    assert_eq!(skip_list.front(), Some(&1));
+    assert_eq!(skip_list.back(), Some(&3));
+    assert_eq!(skip_list.get(3), None);
+
+    skip_list.insert_before(0, 0);
+    skip_list.insert_before(4, 4);
+    skip_list.push_front(-1);
+    assert_eq!(format!("{skip_list:?}"), "[-1, 0, 1, 2, 3, 4]");
+    assert_eq!(skip_list.iter().len(), 6);
+
+    assert_eq!(skip_list.remove_at(3).as_deref(), Some(&2));
+    assert_eq!(skip_list.remove_at(5), None);
+    assert_eq!(skip_list.pop_front().as_deref(), Some(&-1));
+    assert_eq!(skip_list.pop_back().as_deref(), Some(&4));
+    assert_eq!(skip_list, SkipList::from([0, 1, 3]));
+
+    // shared elements are only mutable by copy-on-write
+    let shared_element = skip_list.at(1).unwrap();
+    assert!(skip_list.get_mut(1).is_none());
+    *skip_list.make_mut(1).unwrap() = 10;
+    assert_eq!(*shared_element, 1);
+    assert_eq!(skip_list[1], 10);
+    let own_element = Rc::new(5);
+    skip_list.append_shared(Rc::clone(&own_element));
+    assert!(Rc::ptr_eq(&skip_list.at(3).unwrap(), &own_element));
+
+    let copied_list = skip_list.clone();
+    skip_list.clear();
+    assert!(skip_list.is_empty());
+    assert_eq!(skip_list.pop_back(), None);
+    assert_eq!(
+        copied_list.iter().copied().collect::<Vec<_>>(),
+        [0, 10, 3, 5]
+    );
+}
+
+impl PositionalList for SkipList<u64, BoxFamily> {
+    fn insert_before(&mut self, position: usize, element: u64) {
+        SkipList::insert_before(self, position, element);
+    }
+
+    fn remove_at(&mut self, position: usize) -> Option<u64> {
+        SkipList::remove_at(self, position).map(|element| *element)
+    }
+
+    fn len(&self) -> usize {
+        SkipList::len(self)
+    }
+}
+
+#[test]
+fn tc1_matches_vec() {
+    let mut skip_list: SkipList<u64, BoxFamily> = SkipList::default();
+    let expected = check_random_positional_ops(&mut skip_list, 11, 3000);
+    assert!(skip_list.iter().eq(expected.iter()));
+    assert!((0..expected.len()).all(|index| skip_list[index] == expected[index]));
+    *skip_list.get_mut(0).unwrap() = 0;
+    assert_eq!(skip_list[0], 0);
+}
+
+#[test]
+fn tc2_ordered_by_key() {
+    let mut skip_list: SkipList<(u32, &str)> = SkipList::new();
+    let by_key = |entry: &(u32, &str)| entry.0;
+    for entry in [
+        (5, "five"),
+        (1, "one"),
+        (3, "three"),
+        (3, "drei"),
+        (9, "nine"),
+    ] {
+        skip_list.insert_by_key(entry, by_key);
+    }
+    assert_eq!(
+        skip_list.iter().map(|entry| entry.1).collect::<Vec<_>>(),
+        ["one", "three", "drei", "five", "nine"]
+    );
+    assert_eq!(skip_list.insert_by_key((0, "zero"), by_key), 0);
+
+    assert_eq!(skip_list.position_by_key(&3, by_key), Some(2));
+    assert_eq!(skip_list.find_by_key(&9, by_key), Some(&(9, "nine")));
+    assert_eq!(skip_list.find_by_key(&4, by_key), None);
+    assert_eq!(skip_list.find_by_key(&10, by_key), None);
+
+    assert_eq!(
+        skip_list.remove_by_key(&3, by_key).as_deref(),
+        Some(&(3, "three"))
+    );
+    assert_eq!(
+        skip_list.remove_by_key(&3, by_key).as_deref(),
+        Some(&(3, "drei"))
+    );
+    assert_eq!(skip_list.remove_by_key(&3, by_key), None);
+    assert_eq!(skip_list.len(), 4);
+    assert_eq!(skip_list[3], (9, "nine"));
+}
+
+#[test]
+#[should_panic(expected = "index 4 out of range for list of length 3")]
+fn tc3_insert_out_of_range() {
+    let mut skip_list: SkipList<i32> = SkipList::from([1, 2, 3]);
+    skip_list.insert_before(4, 0);
+}
@@ -0,0 +1,288 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Bound, Index, RangeBounds};
+
+use crate::list::{CompactionPolicy, Iter, List};
+use crate::pointer_family::{PointerFamily, RcFamily};
+
+#[cfg(test)]
+mod tests;
+
+/// A [`List`] that keeps its elements ordered by a key.
+///
+/// The key is computed by a function given on construction, which may also be
+/// a capturing closure. Elements with equal keys keep the order they were
+/// inserted in.
+///
+/// ```
+/// use container::SortedList;
+///
+/// let mut sorted_list = SortedList::new(|element: &(i32, char)| element.0);
+/// sorted_list.insert_sorted((3, 'c'));
+/// sorted_list.insert_sorted((1, 'a'));
+/// sorted_list.insert_sorted((2, 'b'));
+///
+/// assert_eq!(sorted_list.find_by_key(&2), Some(&(2, 'b')));
+/// let in_range: Vec<&(i32, char)> = sorted_list.range_by_key(2..).collect();
+/// assert_eq!(in_range, [&(2, 'b'), &(3, 'c')]);
+/// ```
+pub struct SortedList<T, K, KF = fn(&T) -> K, F: PointerFamily = RcFamily> {
+    sorted_elements: List<T, F>,
+    key_fn: KF,
+    key_type: PhantomData<fn() -> K>,
+}
+
+impl<T, K: Ord, KF: Fn(&T) -> K> SortedList<T, K, KF> {
+    /// Creates an empty list, use [`from_list`](SortedList::from_list) with an
+    /// empty list for other pointer families.
+    pub fn new(key_fn: KF) -> SortedList<T, K, KF> {
+        SortedList::from_list(List::default(), key_fn)
+    }
+}
+
+impl<T, K: Ord, KF: Fn(&T) -> K, F: PointerFamily> SortedList<T, K, KF, F> {
+    /// Sorts the list stable by the key and takes it over.
+    pub fn from_list(mut list: List<T, F>, key_fn: KF) -> SortedList<T, K, KF, F> {
+        list.sort_by_key(&key_fn);
+        SortedList {
+            sorted_elements: list,
+            key_fn,
+            key_type: PhantomData,
+        }
+    }
+
+    /// Inserts the element behind all elements with a smaller or equal key.
+    ///
+    /// Inserting at the end is checked first, so adding elements in ascending
+    /// order takes constant time, otherwise the insert position is searched
+    /// from the head.
+    pub fn insert_sorted(&mut self, element: T) {
+        self.insert_sorted_shared(F::new(element));
+    }
+
+    pub fn insert_sorted_shared(&mut self, element: F::Pointer<T>) {
+        let element_key = (self.key_fn)(&element);
+        let behind_tail = match self.sorted_elements.back() {
+            Some(tail_element) => (self.key_fn)(tail_element) <= element_key,
+            None => true,
+        };
+        if behind_tail {
+            self.sorted_elements.push_back_shared(element);
+            return;
+        }
+
+        let insert_index = self
+            .sorted_elements
+            .iter_ref()
+            .position(|present_element| (self.key_fn)(present_element) > element_key)
+            .expect("the tail has a greater key");
+        self.sorted_elements
+            .insert_before_shared(insert_index, element);
+    }
+
+    /// Returns the first element with the given key.
+    pub fn find_by_key(&self, key: &K) -> Option<&T> {
+        self.iter_ref()
+            .take_while(|element| (self.key_fn)(element) <= *key)
+            .find(|element| (self.key_fn)(element) == *key)
+    }
+
+    /// Iterates in order over the elements whose key lies in the range. The
+    /// iteration stops at the first key behind the range.
+    pub fn range_by_key<'a, R>(&'a self, range: R) -> impl Iterator<Item = &'a T> + 'a
+    where
+        R: RangeBounds<K> + 'a,
+    {
+        let key_fn = &self.key_fn;
+        // Less is in front of the range, Greater behind it
+        let range_ordering = move |element: &T| {
+            let element_key = key_fn(element);
+            let behind_start = match range.start_bound() {
+                Bound::Included(start) => element_key >= *start,
+                Bound::Excluded(start) => element_key > *start,
+                Bound::Unbounded => true,
+            };
+            let before_end = match range.end_bound() {
+                Bound::Included(end) => element_key <= *end,
+                Bound::Excluded(end) => element_key < *end,
+                Bound::Unbounded => true,
+            };
+            match (behind_start, before_end) {
+                (false, _) => Ordering::Less,
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+            }
+        };
+
+        self.iter_ref()
+            .map(move |element| (element, range_ordering(element)))
+            .skip_while(|(_, ordering)| *ordering == Ordering::Less)
+            .take_while(|(_, ordering)| *ordering == Ordering::Equal)
+            .map(|(element, _)| element)
+    }
+
+    /// Merges `other` into this list in a single pass over both, O(n + m).
+    ///
+    /// Whenever the fronts of both lists have an equal key they are fused into
+    /// a single element by `combine`, which gets the element of `self` first.
+    /// Elements are moved, not cloned, only fused elements are newly created.
+    ///
+    /// ```
+    /// use container::{List, SortedList};
+    ///
+    /// let key_fn = |element: &(u8, i32)| element.0;
+    /// let mut sorted_list = SortedList::from_list(List::from_array([(0, 1), (2, 1)]), key_fn);
+    /// let other = SortedList::from_list(List::from_array([(1, 5), (2, 5)]), key_fn);
+    ///
+    /// sorted_list.merge_with(other, |element, other_element| {
+    ///     (element.0, element.1 + other_element.1)
+    /// });
+    /// assert_eq!(sorted_list.as_list(), &List::from_array([(0, 1), (1, 5), (2, 6)]));
+    /// ```
+    pub fn merge_with<C>(&mut self, other: SortedList<T, K, KF, F>, mut combine: C)
+    where
+        C: FnMut(&T, &T) -> T,
+    {
+        let mut own_elements = std::mem::take(&mut self.sorted_elements);
+        let mut other_elements = other.sorted_elements;
+        // both lists only shrink from the front, compacting them would be wasted work
+        let compaction_policy = own_elements.compaction_policy();
+        own_elements.set_compaction_policy(CompactionPolicy::Manual);
+        other_elements.set_compaction_policy(CompactionPolicy::Manual);
+
+        let mut merged_elements = List::default();
+        merged_elements.reserve(own_elements.len() + other_elements.len());
+        merged_elements.set_position_cache(own_elements.has_position_cache());
+
+        loop {
+            let front_ordering = match (own_elements.front(), other_elements.front()) {
+                (Some(own_front), Some(other_front)) => {
+                    (self.key_fn)(own_front).cmp(&(self.key_fn)(other_front))
+                }
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => break,
+            };
+
+            match front_ordering {
+                Ordering::Less => {
+                    let own_front = own_elements.pop_front().expect("front exists");
+                    merged_elements.push_back_shared(own_front);
+                }
+                Ordering::Greater => {
+                    let other_front = other_elements.pop_front().expect("front exists");
+                    merged_elements.push_back_shared(other_front);
+                }
+                Ordering::Equal => {
+                    let fused_element = match (own_elements.front(), other_elements.front()) {
+                        (Some(own_front), Some(other_front)) => combine(own_front, other_front),
+                        _ => unreachable!("equal keys need two fronts"),
+                    };
+                    own_elements.pop_front();
+                    other_elements.pop_front();
+                    merged_elements.push_back(fused_element);
+                }
+            }
+        }
+
+        merged_elements.set_compaction_policy(compaction_policy);
+        self.sorted_elements = merged_elements;
+    }
+
+    pub fn remove_by_key(&mut self, key: &K) -> Option<F::Pointer<T>> {
+        let remove_index = self
+            .iter_ref()
+            .take_while(|element| (self.key_fn)(element) <= *key)
+            .position(|element| (self.key_fn)(element) == *key)?;
+        self.sorted_elements.remove_at(remove_index)
+    }
+}
+
+impl<T, K, KF, F: PointerFamily> SortedList<T, K, KF, F> {
+    pub fn len(&self) -> usize {
+        self.sorted_elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sorted_elements.is_empty()
+    }
+
+    pub fn first(&self) -> Option<&T> {
+        self.sorted_elements.front()
+    }
+
+    pub fn last(&self) -> Option<&T> {
+        self.sorted_elements.back()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.sorted_elements.get(index)
+    }
+
+    pub fn pop_first(&mut self) -> Option<F::Pointer<T>> {
+        self.sorted_elements.pop_front()
+    }
+
+    pub fn pop_last(&mut self) -> Option<F::Pointer<T>> {
+        self.sorted_elements.pop_back()
+    }
+
+    pub fn iter_ref(&self) -> Iter<'_, T, F> {
+        self.sorted_elements.iter_ref()
+    }
+
+    /// The elements in sorted order. There is no mutable counterpart, changing
+    /// an element could change its key.
+    pub fn as_list(&self) -> &List<T, F> {
+        &self.sorted_elements
+    }
+
+    pub fn into_list(self) -> List<T, F> {
+        self.sorted_elements
+    }
+}
+
+impl<T, K, KF: Clone, F: PointerFamily> Clone for SortedList<T, K, KF, F>
+where
+    F::Pointer<T>: Clone,
+{
+    fn clone(&self) -> Self {
+        SortedList {
+            sorted_elements: self.sorted_elements.clone(),
+            key_fn: self.key_fn.clone(),
+            key_type: PhantomData,
+        }
+    }
+}
+
+impl<T: fmt::Debug, K, KF, F: PointerFamily> fmt::Debug for SortedList<T, K, KF, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.sorted_elements.fmt(f)
+    }
+}
+
+impl<T, K, KF, F: PointerFamily> Index<usize> for SortedList<T, K, KF, F> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.sorted_elements[index]
+    }
+}
+
+impl<'a, T, K, KF, F: PointerFamily> IntoIterator for &'a SortedList<T, K, KF, F> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_ref()
+    }
+}
+
+impl<T, K: Ord, KF: Fn(&T) -> K, F: PointerFamily> Extend<T> for SortedList<T, K, KF, F> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for element in iter {
+            self.insert_sorted(element);
+        }
+    }
+}
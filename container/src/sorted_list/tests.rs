@@ -0,0 +1,97 @@
+use std::rc::Rc;
+
+use super::*;
+
+fn first_of(element: &(i32, char)) -> i32 {
+    element.0
+}
+
+#[test]
+fn tc0_insert_sorted() {
+    let mut sorted_list = SortedList::new(first_of);
+    for element in [(2, 'a'), (0, 'b'), (2, 'c'), (1, 'd'), (3, 'e'), (2, 'f')] {
+        sorted_list.insert_sorted(element);
+    }
+
+    let collected: Vec<char> = sorted_list.iter_ref().map(|element| element.1).collect();
+    assert_eq!(collected, ['b', 'd', 'a', 'c', 'f', 'e']);
+    assert_eq!(sorted_list.len(), 6);
+    assert_eq!(sorted_list.first(), Some(&(0, 'b')));
+    assert_eq!(sorted_list.last(), Some(&(3, 'e')));
+    assert_eq!(sorted_list[2], (2, 'a'));
+
+    assert_eq!(sorted_list.find_by_key(&2), Some(&(2, 'a')));
+    assert_eq!(sorted_list.find_by_key(&4), None);
+    let in_range: Vec<char> = sorted_list
+        .range_by_key(1..=2)
+        .map(|element| element.1)
+        .collect();
+    assert_eq!(in_range, ['d', 'a', 'c', 'f']);
+    assert_eq!(sorted_list.range_by_key(..1).count(), 1);
+    assert_eq!(sorted_list.range_by_key(4..).count(), 0);
+
+    let removed = sorted_list.remove_by_key(&2).unwrap();
+    assert_eq!(*removed, (2, 'a'));
+    assert!(sorted_list.remove_by_key(&5).is_none());
+    assert_eq!(*sorted_list.pop_first().unwrap(), (0, 'b'));
+    assert_eq!(*sorted_list.pop_last().unwrap(), (3, 'e'));
+}
+
+#[test]
+fn tc1_merge_with() {
+    let shared_element = Rc::new((5, 'z'));
+    let mut sorted_list =
+        SortedList::from_list(List::from_array([(4, 'a'), (1, 'b'), (3, 'c')]), first_of);
+    let mut other = SortedList::new(first_of);
+    other.extend([(0, 'd'), (3, 'e'), (6, 'f')]);
+    other.insert_sorted_shared(Rc::clone(&shared_element));
+
+    let mut fuse_count = 0;
+    sorted_list.merge_with(other, |element, other_element| {
+        fuse_count += 1;
+        (element.0, other_element.1)
+    });
+
+    assert_eq!(fuse_count, 1);
+    assert_eq!(
+        sorted_list.as_list(),
+        &List::from_array([(0, 'd'), (1, 'b'), (3, 'e'), (4, 'a'), (5, 'z'), (6, 'f')])
+    );
+    assert_eq!(Rc::strong_count(&shared_element), 2);
+    assert_eq!(sorted_list.as_list().vacant_slots(), 0);
+
+    let mut empty = SortedList::new(first_of);
+    empty.merge_with(sorted_list.clone(), |element, _| *element);
+    assert_eq!(empty.as_list(), sorted_list.as_list());
+    sorted_list.merge_with(SortedList::new(first_of), |element, _| *element);
+    assert_eq!(sorted_list.len(), 6);
+    assert_eq!(
+        format!(
+            "{:?}",
+            SortedList::from_list(List::from_array([(1, 'x')]), first_of)
+        ),
+        "[(1, 'x')]"
+    );
+}
+
+#[test]
+fn tc2_capturing_key_fn() {
+    // the key is the distance to a pivot only known at runtime
+    let pivot = 10;
+    let distance_key = move |element: &i32| (element - pivot).abs();
+    let mut sorted_list = SortedList::new(distance_key);
+    sorted_list.extend([3, 12, 10, 20, 9]);
+    assert_eq!(
+        sorted_list.iter_ref().copied().collect::<Vec<_>>(),
+        [10, 9, 12, 3, 20]
+    );
+    assert_eq!(sorted_list.find_by_key(&1), Some(&9));
+
+    let other = SortedList::from_list(List::from_array([11, 0]), distance_key);
+    // 11 and 0 have the keys of 9 and 20, both pairs are fused
+    sorted_list.merge_with(other.clone(), |element, _| *element);
+    assert_eq!(sorted_list.len(), 5);
+    assert_eq!(other.len(), 2);
+    assert_eq!(sorted_list.remove_by_key(&10).as_deref(), Some(&20));
+    assert_eq!(sorted_list.range_by_key(..2).count(), 2);
+}
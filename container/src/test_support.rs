@@ -0,0 +1,41 @@
+//! Helpers shared by the tests of several list types.
+
+/// A list with positional insertion and removal, driven against a `Vec` by
+/// [`check_random_positional_ops`].
+pub(crate) trait PositionalList {
+    fn insert_before(&mut self, position: usize, element: u64);
+    fn remove_at(&mut self, position: usize) -> Option<u64>;
+    fn len(&self) -> usize;
+}
+
+/// Applies `steps` pseudo random insertions and removals, about a third of
+/// them removals, to the list and to a `Vec`. Checks the removed elements and
+/// the length after every step and returns the `Vec` for further checks.
+pub(crate) fn check_random_positional_ops(
+    tested_list: &mut impl PositionalList,
+    seed: u64,
+    steps: u64,
+) -> Vec<u64> {
+    let mut expected = Vec::new();
+    let mut state = seed;
+    for step in 0..steps {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let position = (state >> 33) as usize % (expected.len() + 1);
+        match (state >> 20) % 3 {
+            0 if position < expected.len() => {
+                assert_eq!(
+                    tested_list.remove_at(position),
+                    Some(expected.remove(position))
+                );
+            }
+            _ => {
+                tested_list.insert_before(position, step);
+                expected.insert(position, step);
+            }
+        }
+        assert_eq!(tested_list.len(), expected.len());
+    }
+    expected
+}
@@ -0,0 +1,186 @@
+use super::UnrolledList;
+
+/// Mutable cursor over an [`UnrolledList`], created by
+/// [`UnrolledList::pos_mut`] and [`UnrolledList::cursor_mut`].
+///
+/// The position is either an element or in front of the head. Like the cursor
+/// of a [`List`](crate::List), which yields pointers to the elements, it
+/// iterates over clones of the elements behind the position, the current
+/// element is accessed in place by [`current_mut`](MutUnrolledPos::current_mut).
+/// Moving the cursor takes constant time, insertions and removals only move
+/// the elements of the current node and its neighbours.
+pub struct MutUnrolledPos<'a, T, const N: usize = 16> {
+    // node and offset of the current element, None in front of the head
+    location: Option<(usize, usize)>,
+    // node and offset of the last element yielded by next_back, None while
+    // the back end is untouched or after the list was changed
+    back_location: Option<(usize, usize)>,
+    // elements yielded by next_back, changes at the position don't move them
+    back_count: usize,
+    remaining: usize,
+    referenced_entry: &'a mut UnrolledList<T, N>,
+}
+
+impl<'a, T, const N: usize> MutUnrolledPos<'a, T, N> {
+    pub(super) fn new(ref_list: &'a mut UnrolledList<T, N>) -> MutUnrolledPos<'a, T, N> {
+        MutUnrolledPos {
+            location: None,
+            back_location: None,
+            back_count: 0,
+            remaining: ref_list.len,
+            referenced_entry: ref_list,
+        }
+    }
+
+    pub(super) fn start_at(
+        position: usize,
+        ref_list: &'a mut UnrolledList<T, N>,
+    ) -> Option<MutUnrolledPos<'a, T, N>> {
+        let location = ref_list.locate(position)?;
+
+        Some(MutUnrolledPos {
+            location: Some(location),
+            back_location: None,
+            back_count: 0,
+            remaining: ref_list.len - position - 1,
+            referenced_entry: ref_list,
+        })
+    }
+
+    pub fn current(&self) -> Option<&T> {
+        let (node_index, offset) = self.location?;
+        Some(self.referenced_entry.all_nodes[node_index].get(offset))
+    }
+
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        let (node_index, offset) = self.location?;
+        Some(self.referenced_entry.all_nodes[node_index].get_mut(offset))
+    }
+
+    pub fn remove(mut self) -> Option<T> {
+        self.remove_current()
+    }
+
+    /// Removes the current element.
+    ///
+    /// The position moves back to the predecessor of the removed element, so a
+    /// following [`next`](Iterator::next) yields its successor. This allows
+    /// removing elements while iterating.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let (node_index, offset) = self.location?;
+        let list = &mut *self.referenced_entry;
+        let (removed_element, successor) = list.remove_at_location(node_index, offset);
+
+        self.location = match successor {
+            Some(successor) => list.prev_location(successor),
+            None if list.is_empty() => None,
+            None => Some((list.tail_index, list.all_nodes[list.tail_index].len() - 1)),
+        };
+        self.back_location = None;
+        Some(removed_element)
+    }
+
+    /// Inserts an element in front of the current element, the position stays
+    /// at the current element. Without a current element the new element
+    /// becomes the head.
+    pub fn insert_before(&mut self, element: T) {
+        let list = &mut *self.referenced_entry;
+        match self.location {
+            Some((node_index, offset)) => {
+                let new_location = list.insert_at_location(node_index, offset, element);
+                self.location = list.next_location(new_location);
+            }
+            None => self.push_head(element),
+        }
+        self.back_location = None;
+    }
+
+    /// Inserts an element behind the current element, so it is yielded by the
+    /// following [`next`](Iterator::next). Without a current element the new
+    /// element becomes the head.
+    pub fn insert_after(&mut self, element: T) {
+        let list = &mut *self.referenced_entry;
+        match self.location {
+            Some((node_index, offset)) => {
+                let new_location = list.insert_at_location(node_index, offset + 1, element);
+                self.location = list.prev_location(new_location);
+                self.remaining += 1;
+            }
+            None => self.push_head(element),
+        }
+        self.back_location = None;
+    }
+
+    /// Moves the position one element towards the head and returns the element there.
+    ///
+    /// Moving back from the head leaves the position in front of the list, so a
+    /// following [`next`](Iterator::next) yields the head again.
+    pub fn prev(&mut self) -> Option<T>
+    where
+        T: Clone,
+    {
+        let location = self.location?;
+        // the element moved away from is yielded again by the following next
+        self.remaining += 1;
+        self.location = self.referenced_entry.prev_location(location);
+        self.current().cloned()
+    }
+
+    fn push_head(&mut self, element: T) {
+        self.remaining += 1;
+        self.referenced_entry.push_front(element);
+    }
+}
+
+impl<'a, T: Clone, const N: usize> Iterator for MutUnrolledPos<'a, T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let list = &*self.referenced_entry;
+        self.location = match self.location {
+            None => Some((list.head_index, 0)),
+            Some(location) => list.next_location(location),
+        };
+        self.remaining -= 1;
+
+        self.current().cloned()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+/// Yields the elements from the tail towards the position. Both ends stop
+/// where they meet, elements inserted behind the position are yielded by either end.
+impl<'a, T: Clone, const N: usize> DoubleEndedIterator for MutUnrolledPos<'a, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let list = &*self.referenced_entry;
+        let found_location = match self.back_location {
+            None => list.locate(list.len - 1 - self.back_count),
+            Some(back_location) => list.prev_location(back_location),
+        }?;
+
+        self.back_location = Some(found_location);
+        self.back_count += 1;
+        self.remaining -= 1;
+
+        let (node_index, offset) = found_location;
+        Some(list.all_nodes[node_index].get(offset).clone())
+    }
+}
+
+// Not fused, moving the position back or inserting behind it continues the iteration.
+impl<'a, T: Clone, const N: usize> ExactSizeIterator for MutUnrolledPos<'a, T, N> {}
@@ -0,0 +1,108 @@
+use std::iter::{Flatten, FusedIterator};
+use std::vec;
+
+use super::UnrolledList;
+
+/// Borrowing iterator over the elements of an [`UnrolledList`], created by
+/// [`UnrolledList::iter`].
+pub struct UnrolledIter<'a, T, const N: usize = 16> {
+    node_index: usize,
+    offset: usize,
+    remaining: usize,
+    referenced_entry: &'a UnrolledList<T, N>,
+}
+
+impl<'a, T, const N: usize> UnrolledIter<'a, T, N> {
+    pub fn new(ref_list: &'a UnrolledList<T, N>) -> UnrolledIter<'a, T, N> {
+        UnrolledIter {
+            node_index: ref_list.head_index,
+            offset: 0,
+            remaining: ref_list.len,
+            referenced_entry: ref_list,
+        }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for UnrolledIter<'a, T, N> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let found_node = &self.referenced_entry.all_nodes[self.node_index];
+        let found_element = found_node.get(self.offset);
+        self.offset += 1;
+        if self.offset == found_node.len() {
+            self.node_index = found_node.next_index;
+            self.offset = 0;
+        }
+        self.remaining -= 1;
+
+        Some(found_element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for UnrolledIter<'a, T, N> {}
+
+impl<'a, T, const N: usize> FusedIterator for UnrolledIter<'a, T, N> {}
+
+/// Mutable iterator over the elements of an [`UnrolledList`], created by
+/// [`UnrolledList::iter_mut`].
+pub struct UnrolledIterMut<'a, T> {
+    // the occupied part of every node, in list order
+    node_elements: Flatten<vec::IntoIter<&'a mut [T]>>,
+    remaining: usize,
+}
+
+impl<'a, T> UnrolledIterMut<'a, T> {
+    pub fn new<const N: usize>(ref_list: &'a mut UnrolledList<T, N>) -> UnrolledIterMut<'a, T> {
+        let remaining = ref_list.len;
+        let mut node_order = Vec::with_capacity(ref_list.node_count());
+        let mut node_index = ref_list.head_index;
+        for _ in 0..ref_list.node_count() {
+            node_order.push(node_index);
+            node_index = ref_list.all_nodes[node_index].next_index;
+        }
+
+        // the arena is split into independent borrows of every node, so the
+        // nodes can be handed out in list order
+        let mut node_slots: Vec<Option<&'a mut [T]>> = ref_list
+            .all_nodes
+            .iter_mut()
+            .map(|node| Some(node.as_mut_slice()))
+            .collect();
+        let ordered_elements: Vec<&'a mut [T]> = node_order
+            .into_iter()
+            .filter_map(|node_index| node_slots[node_index].take())
+            .collect();
+
+        UnrolledIterMut {
+            node_elements: ordered_elements.into_iter().flatten(),
+            remaining,
+        }
+    }
+}
+
+impl<'a, T> Iterator for UnrolledIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let found_element = self.node_elements.next()?;
+        self.remaining -= 1;
+        Some(found_element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for UnrolledIterMut<'a, T> {}
+
+impl<'a, T> FusedIterator for UnrolledIterMut<'a, T> {}
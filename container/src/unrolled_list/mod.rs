@@ -0,0 +1,545 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::list::ListError;
+
+mod cursor;
+pub use self::cursor::MutUnrolledPos;
+
+mod iter;
+pub use self::iter::{UnrolledIter, UnrolledIterMut};
+
+mod node;
+use self::node::UnrolledNode;
+
+#[cfg(test)]
+mod tests;
+
+/// A doubly linked list storing up to `N` elements inline in every node.
+///
+/// In contrast to [`List`](crate::List) the elements are stored by value and
+/// next to each other, so scanning the list touches a fraction of the memory
+/// and indexed access skips whole nodes. Like `List` the nodes live in an
+/// arena and are linked by their indices.
+///
+/// A full node is split in half on insertion. A node left with less than half
+/// of `N` elements by a removal is merged with a neighbour or takes elements
+/// from one, so every node but a tail started by appending holds at least
+/// `N / 2` elements.
+///
+/// ```
+/// use container::UnrolledList;
+///
+/// let mut unrolled_list: UnrolledList<u32, 4> = (0..10).collect();
+/// unrolled_list.insert_before(5, 50);
+/// unrolled_list.remove_at(0);
+///
+/// assert_eq!(unrolled_list[4], 50);
+/// assert_eq!(unrolled_list.iter().sum::<u32>(), 95);
+/// ```
+pub struct UnrolledList<T, const N: usize = 16> {
+    head_index: usize,
+    tail_index: usize,
+    all_nodes: Vec<UnrolledNode<T, N>>,
+    free_nodes: Vec<usize>,
+    len: usize,
+}
+
+impl<T, const N: usize> Default for UnrolledList<T, N> {
+    fn default() -> Self {
+        UnrolledList {
+            head_index: 0,
+            tail_index: 0,
+            all_nodes: Vec::new(),
+            free_nodes: Vec::new(),
+            len: 0,
+        }
+    }
+}
+
+impl<T> UnrolledList<T> {
+    pub fn new() -> UnrolledList<T> {
+        UnrolledList::default()
+    }
+}
+
+impl<T, const N: usize> UnrolledList<T, N> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn append(&mut self, element: T) {
+        self.push_back(element);
+    }
+
+    pub fn push_back(&mut self, element: T) {
+        match self.is_empty() {
+            true => self.push_first(element),
+            false => {
+                let tail_index = self.tail_index;
+                let tail_len = self.all_nodes[tail_index].len();
+                self.insert_at_location(tail_index, tail_len, element);
+            }
+        }
+    }
+
+    pub fn push_front(&mut self, element: T) {
+        match self.is_empty() {
+            true => self.push_first(element),
+            false => {
+                self.insert_at_location(self.head_index, 0, element);
+            }
+        }
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        match self.is_empty() {
+            true => None,
+            false => Some(self.remove_at_location(self.head_index, 0).0),
+        }
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        match self.is_empty() {
+            true => None,
+            false => {
+                let tail_index = self.tail_index;
+                let tail_len = self.all_nodes[tail_index].len();
+                Some(self.remove_at_location(tail_index, tail_len - 1).0)
+            }
+        }
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|index| self.get(index))
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.locate(index)
+            .map(|(node_index, offset)| self.all_nodes[node_index].get(offset))
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.locate(index)
+            .map(|(node_index, offset)| self.all_nodes[node_index].get_mut(offset))
+    }
+
+    /// Inserts the element in front of the element at the index, an index equal
+    /// to the length appends it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is greater than the length.
+    pub fn insert_before(&mut self, insert_index: usize, element: T) {
+        if insert_index > self.len {
+            panic!(
+                "{}",
+                ListError::OutOfRange {
+                    index: insert_index,
+                    len: self.len
+                }
+            );
+        }
+        match self.locate(insert_index) {
+            Some((node_index, offset)) => {
+                self.insert_at_location(node_index, offset, element);
+            }
+            None => self.push_back(element),
+        }
+    }
+
+    pub fn remove_at(&mut self, index: usize) -> Option<T> {
+        let (node_index, offset) = self.locate(index)?;
+        Some(self.remove_at_location(node_index, offset).0)
+    }
+
+    pub fn clear(&mut self) {
+        *self = UnrolledList::default();
+    }
+
+    pub fn iter(&self) -> UnrolledIter<'_, T, N> {
+        UnrolledIter::new(self)
+    }
+
+    pub fn iter_mut(&mut self) -> UnrolledIterMut<'_, T> {
+        UnrolledIterMut::new(self)
+    }
+
+    /// Returns a cursor positioned at the element at the index.
+    pub fn pos_mut(&mut self, pos: usize) -> Option<MutUnrolledPos<'_, T, N>> {
+        MutUnrolledPos::start_at(pos, self)
+    }
+
+    /// Returns a cursor positioned in front of the head.
+    pub fn cursor_mut(&mut self) -> MutUnrolledPos<'_, T, N> {
+        MutUnrolledPos::new(self)
+    }
+
+    /// Number of nodes holding the elements.
+    pub fn node_count(&self) -> usize {
+        self.all_nodes.len() - self.free_nodes.len()
+    }
+
+    /// Finds node and offset of the index, walking whole nodes from the closer end.
+    fn locate(&self, index: usize) -> Option<(usize, usize)> {
+        if index >= self.len {
+            return None;
+        }
+
+        if index < self.len / 2 {
+            let mut node_index = self.head_index;
+            let mut offset = index;
+            while offset >= self.all_nodes[node_index].len() {
+                offset -= self.all_nodes[node_index].len();
+                node_index = self.all_nodes[node_index].next_index;
+            }
+            Some((node_index, offset))
+        } else {
+            let mut node_index = self.tail_index;
+            let mut offset_from_end = self.len - 1 - index;
+            while offset_from_end >= self.all_nodes[node_index].len() {
+                offset_from_end -= self.all_nodes[node_index].len();
+                node_index = self.all_nodes[node_index].prev_index;
+            }
+            Some((
+                node_index,
+                self.all_nodes[node_index].len() - 1 - offset_from_end,
+            ))
+        }
+    }
+
+    /// The location following the given one, `None` behind the tail.
+    fn next_location(&self, (node_index, offset): (usize, usize)) -> Option<(usize, usize)> {
+        match (
+            offset + 1 < self.all_nodes[node_index].len(),
+            node_index == self.tail_index,
+        ) {
+            (true, _) => Some((node_index, offset + 1)),
+            (false, true) => None,
+            (false, false) => Some((self.all_nodes[node_index].next_index, 0)),
+        }
+    }
+
+    /// The location in front of the given one, `None` in front of the head.
+    fn prev_location(&self, (node_index, offset): (usize, usize)) -> Option<(usize, usize)> {
+        match (offset > 0, node_index == self.head_index) {
+            (true, _) => Some((node_index, offset - 1)),
+            (false, true) => None,
+            (false, false) => {
+                let prev_index = self.all_nodes[node_index].prev_index;
+                Some((prev_index, self.all_nodes[prev_index].len() - 1))
+            }
+        }
+    }
+
+    fn push_first(&mut self, element: T) {
+        let node_index = self.allocate_node();
+        self.head_index = node_index;
+        self.tail_index = node_index;
+        self.all_nodes[node_index].push(element);
+        self.len += 1;
+    }
+
+    /// Inserts the element at the offset (at most the length) of a linked node
+    /// and returns where the element ended up.
+    fn insert_at_location(
+        &mut self,
+        node_index: usize,
+        offset: usize,
+        element: T,
+    ) -> (usize, usize) {
+        self.len += 1;
+        if self.all_nodes[node_index].len() < N {
+            self.all_nodes[node_index].insert(offset, element);
+            return (node_index, offset);
+        }
+
+        // appending behind a full tail starts a new node instead of splitting
+        let appending = offset == N && node_index == self.tail_index;
+        let new_index = self.allocate_node();
+        self.link_node_after(node_index, new_index);
+        if appending {
+            self.all_nodes[new_index].push(element);
+            return (new_index, 0);
+        }
+
+        // the upper half of the full node moves into the new node
+        let half_len = N / 2;
+        let (full_node, new_node) = self.node_pair(node_index, new_index);
+        new_node.append_from(full_node, half_len);
+
+        match offset <= half_len {
+            true => {
+                self.all_nodes[node_index].insert(offset, element);
+                (node_index, offset)
+            }
+            false => {
+                self.all_nodes[new_index].insert(offset - half_len, element);
+                (new_index, offset - half_len)
+            }
+        }
+    }
+
+    /// Removes the element at the location and returns it together with the
+    /// location its successor ends up at, `None` if it was the last element.
+    ///
+    /// Rebalancing the node may move elements on both sides of the removal,
+    /// other locations have to be looked up again.
+    fn remove_at_location(
+        &mut self,
+        node_index: usize,
+        offset: usize,
+    ) -> (T, Option<(usize, usize)>) {
+        let removed_element = self.all_nodes[node_index].remove(offset);
+        self.len -= 1;
+
+        let successor = match (
+            offset < self.all_nodes[node_index].len(),
+            node_index == self.tail_index,
+        ) {
+            (true, _) => Some((node_index, offset)),
+            (false, true) => None,
+            (false, false) => Some((self.all_nodes[node_index].next_index, 0)),
+        };
+        (removed_element, self.rebalance(node_index, successor))
+    }
+
+    /// Frees an emptied node and refills a node holding less than half of `N`
+    /// elements from its neighbours: it is merged with the predecessor or the
+    /// successor if they fit into one node, otherwise it takes the missing
+    /// elements from one of them. Returns where the element at `tracked` ends up.
+    fn rebalance(
+        &mut self,
+        node_index: usize,
+        tracked: Option<(usize, usize)>,
+    ) -> Option<(usize, usize)> {
+        let node_len = self.all_nodes[node_index].len();
+        if node_len == 0 {
+            self.unlink_node(node_index);
+            return tracked;
+        }
+        // a single node may hold any number of elements
+        if node_len >= N / 2 || self.head_index == self.tail_index {
+            return tracked;
+        }
+
+        let prev_index = self.all_nodes[node_index].prev_index;
+        let next_index = self.all_nodes[node_index].next_index;
+        let has_prev = node_index != self.head_index;
+        let has_next = node_index != self.tail_index;
+        let missing_len = N / 2 - node_len;
+
+        if has_prev && self.all_nodes[prev_index].len() + node_len <= N {
+            let prev_len = self.all_nodes[prev_index].len();
+            let (prev_node, node) = self.node_pair(prev_index, node_index);
+            prev_node.append_from(node, 0);
+            self.unlink_node(node_index);
+            tracked.map(
+                |(tracked_index, tracked_offset)| match tracked_index == node_index {
+                    true => (prev_index, prev_len + tracked_offset),
+                    false => (tracked_index, tracked_offset),
+                },
+            )
+        } else if has_next && node_len + self.all_nodes[next_index].len() <= N {
+            let (node, next_node) = self.node_pair(node_index, next_index);
+            node.append_from(next_node, 0);
+            self.unlink_node(next_index);
+            tracked.map(
+                |(tracked_index, tracked_offset)| match tracked_index == next_index {
+                    true => (node_index, node_len + tracked_offset),
+                    false => (tracked_index, tracked_offset),
+                },
+            )
+        } else if has_prev {
+            // the neighbour doesn't fit in, so it keeps more than half of N
+            // elements after handing over the missing ones
+            let prev_len = self.all_nodes[prev_index].len();
+            let (prev_node, node) = self.node_pair(prev_index, node_index);
+            node.prepend_from(prev_node, prev_len - missing_len);
+            tracked.map(
+                |(tracked_index, tracked_offset)| match tracked_index == node_index {
+                    true => (node_index, missing_len + tracked_offset),
+                    false => (tracked_index, tracked_offset),
+                },
+            )
+        } else {
+            let (node, next_node) = self.node_pair(node_index, next_index);
+            node.append_front_of(next_node, missing_len);
+            tracked.map(|(tracked_index, tracked_offset)| {
+                match (tracked_index == next_index, tracked_offset < missing_len) {
+                    (true, true) => (node_index, node_len + tracked_offset),
+                    (true, false) => (next_index, tracked_offset - missing_len),
+                    (false, _) => (tracked_index, tracked_offset),
+                }
+            })
+        }
+    }
+
+    fn allocate_node(&mut self) -> usize {
+        const { assert!(N >= 2, "an unrolled node holds at least two elements") };
+        match self.free_nodes.pop() {
+            Some(free_index) => {
+                let free_node = &mut self.all_nodes[free_index];
+                free_node.next_index = free_index;
+                free_node.prev_index = free_index;
+                free_index
+            }
+            None => {
+                let new_index = self.all_nodes.len();
+                self.all_nodes.push(UnrolledNode::new(new_index));
+                new_index
+            }
+        }
+    }
+
+    /// Borrows two distinct nodes mutably at once.
+    fn node_pair(
+        &mut self,
+        first_index: usize,
+        second_index: usize,
+    ) -> (&mut UnrolledNode<T, N>, &mut UnrolledNode<T, N>) {
+        match first_index < second_index {
+            true => {
+                let (front_nodes, back_nodes) = self.all_nodes.split_at_mut(second_index);
+                (&mut front_nodes[first_index], &mut back_nodes[0])
+            }
+            false => {
+                let (front_nodes, back_nodes) = self.all_nodes.split_at_mut(first_index);
+                (&mut back_nodes[0], &mut front_nodes[second_index])
+            }
+        }
+    }
+
+    fn link_node_after(&mut self, anchor_index: usize, new_index: usize) {
+        if anchor_index == self.tail_index {
+            self.tail_index = new_index;
+        } else {
+            let next_index = self.all_nodes[anchor_index].next_index;
+            self.all_nodes[new_index].next_index = next_index;
+            self.all_nodes[next_index].prev_index = new_index;
+        }
+        self.all_nodes[new_index].prev_index = anchor_index;
+        self.all_nodes[anchor_index].next_index = new_index;
+    }
+
+    /// Detaches an empty node from the list and marks it as free.
+    fn unlink_node(&mut self, node_index: usize) {
+        let prev_index = self.all_nodes[node_index].prev_index;
+        let next_index = self.all_nodes[node_index].next_index;
+
+        match (node_index == self.head_index, node_index == self.tail_index) {
+            // the last node is removed, head and tail are meaningless now
+            (true, true) => {}
+            (true, false) => {
+                self.head_index = next_index;
+                self.all_nodes[next_index].prev_index = next_index;
+            }
+            (false, true) => {
+                self.tail_index = prev_index;
+                self.all_nodes[prev_index].next_index = prev_index;
+            }
+            (false, false) => {
+                self.all_nodes[prev_index].next_index = next_index;
+                self.all_nodes[next_index].prev_index = prev_index;
+            }
+        }
+
+        self.free_nodes.push(node_index);
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for UnrolledList<T, N> {
+    fn clone(&self) -> Self {
+        UnrolledList {
+            head_index: self.head_index,
+            tail_index: self.tail_index,
+            all_nodes: self.all_nodes.clone(),
+            free_nodes: self.free_nodes.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for UnrolledList<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for UnrolledList<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for UnrolledList<T, N> {}
+
+impl<T, const N: usize> Index<usize> for UnrolledList<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).unwrap_or_else(|| {
+            panic!(
+                "{}",
+                ListError::OutOfRange {
+                    index,
+                    len: self.len
+                }
+            )
+        })
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for UnrolledList<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let list_len = self.len;
+        self.get_mut(index).unwrap_or_else(|| {
+            panic!(
+                "{}",
+                ListError::OutOfRange {
+                    index,
+                    len: list_len
+                }
+            )
+        })
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for UnrolledList<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut new_list = UnrolledList::default();
+        new_list.extend(iter);
+        new_list
+    }
+}
+
+impl<T, const N: usize> Extend<T> for UnrolledList<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for element in iter {
+            self.push_back(element);
+        }
+    }
+}
+
+impl<T, const N: usize, const COUNT: usize> From<[T; COUNT]> for UnrolledList<T, N> {
+    fn from(given_array: [T; COUNT]) -> Self {
+        given_array.into_iter().collect()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a UnrolledList<T, N> {
+    type Item = &'a T;
+    type IntoIter = UnrolledIter<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,169 @@
+use std::mem::MaybeUninit;
+use std::ptr;
+use std::slice;
+
+/// A node of an [`UnrolledList`](super::UnrolledList), holding its elements
+/// in the first `len` places of an inline array.
+///
+/// The places are `MaybeUninit` instead of `Option`, which would add a
+/// discriminant to every element and for small values double the size of a
+/// node. All unsafe code of the list lives here, it relies on the invariant
+/// that exactly the places `..len` are initialised.
+///
+/// The head node references itself as predecessor and the tail node itself as
+/// successor, same as the entries of a List. Only linked nodes hold elements.
+pub(super) struct UnrolledNode<T, const N: usize> {
+    elements: [MaybeUninit<T>; N],
+    len: usize,
+    pub(super) next_index: usize,
+    pub(super) prev_index: usize,
+}
+
+impl<T, const N: usize> UnrolledNode<T, N> {
+    pub(super) fn new(node_index: usize) -> UnrolledNode<T, N> {
+        UnrolledNode {
+            elements: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+            next_index: node_index,
+            prev_index: node_index,
+        }
+    }
+
+    pub(super) fn len(&self) -> usize {
+        self.len
+    }
+
+    pub(super) fn as_slice(&self) -> &[T] {
+        // SAFETY: the places ..len are initialised
+        unsafe { slice::from_raw_parts(self.elements.as_ptr().cast::<T>(), self.len) }
+    }
+
+    pub(super) fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: the places ..len are initialised
+        unsafe { slice::from_raw_parts_mut(self.elements.as_mut_ptr().cast::<T>(), self.len) }
+    }
+
+    pub(super) fn get(&self, offset: usize) -> &T {
+        &self.as_slice()[offset]
+    }
+
+    pub(super) fn get_mut(&mut self, offset: usize) -> &mut T {
+        &mut self.as_mut_slice()[offset]
+    }
+
+    pub(super) fn push(&mut self, element: T) {
+        // indexing panics if the node is full
+        self.elements[self.len].write(element);
+        self.len += 1;
+    }
+
+    pub(super) fn insert(&mut self, offset: usize, element: T) {
+        assert!(
+            offset <= self.len && self.len < N,
+            "insert into a full node"
+        );
+        // SAFETY: the places offset..len are shifted into offset + 1..=len,
+        // which is within the array as the node isn't full. The gap at the
+        // offset is filled right away.
+        unsafe {
+            let offset_ptr = self.elements.as_mut_ptr().add(offset).cast::<T>();
+            ptr::copy(offset_ptr, offset_ptr.add(1), self.len - offset);
+            offset_ptr.write(element);
+        }
+        self.len += 1;
+    }
+
+    pub(super) fn remove(&mut self, offset: usize) -> T {
+        assert!(offset < self.len, "offset within the node");
+        // SAFETY: the place at the offset is initialised, it is read out and
+        // closed by shifting the initialised places behind it.
+        let removed_element = unsafe {
+            let offset_ptr = self.elements.as_mut_ptr().add(offset).cast::<T>();
+            let removed_element = offset_ptr.read();
+            ptr::copy(offset_ptr.add(1), offset_ptr, self.len - offset - 1);
+            removed_element
+        };
+        self.len -= 1;
+        removed_element
+    }
+
+    /// Moves the elements of `source` from offset `from` on behind the
+    /// elements of this node.
+    pub(super) fn append_from(&mut self, source: &mut UnrolledNode<T, N>, from: usize) {
+        assert!(from <= source.len, "offset within the source node");
+        let moved_len = source.len - from;
+        assert!(
+            self.len + moved_len <= N,
+            "moved elements fit into the node"
+        );
+        // SAFETY: the moved places are initialised in source and fit behind
+        // the initialised places of self. Both nodes are borrowed mutably, so
+        // they don't overlap. source forgets the moved places right after.
+        unsafe {
+            ptr::copy_nonoverlapping(
+                source.elements.as_ptr().add(from),
+                self.elements.as_mut_ptr().add(self.len),
+                moved_len,
+            );
+        }
+        source.len = from;
+        self.len += moved_len;
+    }
+
+    /// Moves the elements of `source` from offset `from` on in front of the
+    /// elements of this node.
+    pub(super) fn prepend_from(&mut self, source: &mut UnrolledNode<T, N>, from: usize) {
+        assert!(from <= source.len, "offset within the source node");
+        let moved_len = source.len - from;
+        assert!(
+            self.len + moved_len <= N,
+            "moved elements fit into the node"
+        );
+        // SAFETY: the initialised places of self are shifted behind the gap
+        // for the moved places, which fit as checked above. The moved places
+        // are initialised in source, source forgets them right after.
+        unsafe {
+            let front_ptr = self.elements.as_mut_ptr();
+            ptr::copy(front_ptr, front_ptr.add(moved_len), self.len);
+            ptr::copy_nonoverlapping(source.elements.as_ptr().add(from), front_ptr, moved_len);
+        }
+        source.len = from;
+        self.len += moved_len;
+    }
+
+    /// Moves the first `count` elements of `source` behind the elements of
+    /// this node.
+    pub(super) fn append_front_of(&mut self, source: &mut UnrolledNode<T, N>, count: usize) {
+        assert!(count <= source.len, "count within the source node");
+        assert!(self.len + count <= N, "moved elements fit into the node");
+        // SAFETY: the moved places are initialised in source and fit behind
+        // the initialised places of self. The remaining places of source are
+        // shifted to its front, so its places ..len - count are initialised.
+        unsafe {
+            let source_ptr = source.elements.as_mut_ptr();
+            ptr::copy_nonoverlapping(source_ptr, self.elements.as_mut_ptr().add(self.len), count);
+            ptr::copy(source_ptr.add(count), source_ptr, source.len - count);
+        }
+        source.len -= count;
+        self.len += count;
+    }
+}
+
+impl<T, const N: usize> Drop for UnrolledNode<T, N> {
+    fn drop(&mut self) {
+        // SAFETY: the places ..len are initialised and dropped only here
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) }
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for UnrolledNode<T, N> {
+    fn clone(&self) -> Self {
+        let mut cloned_node = UnrolledNode::new(self.next_index);
+        cloned_node.prev_index = self.prev_index;
+        // len only counts clones that succeeded, a panicking clone drops them
+        for element in self.as_slice() {
+            cloned_node.push(element.clone());
+        }
+        cloned_node
+    }
+}
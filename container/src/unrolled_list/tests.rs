@@ -0,0 +1,228 @@
+use super::*;
+use crate::test_support::{PositionalList, check_random_positional_ops};
+
+#[test]
+fn tc0_insert_remove_split_merge() {
+    let mut unrolled_list: UnrolledList<usize, 4> = (0..10).collect();
+    assert_eq!(unrolled_list.len(), 10);
+    assert_eq!(unrolled_list.node_count(), 3);
+    assert_eq!(unrolled_list.front(), Some(&0));
+    assert_eq!(unrolled_list.back(), Some(&9));
+    assert_eq!(unrolled_list.get(10), None);
+
+    // inserting into a full node splits it
+    unrolled_list.insert_before(1, 100);
+    assert_eq!(unrolled_list.node_count(), 4);
+    unrolled_list.insert_before(11, 200);
+    unrolled_list.push_front(300);
+    assert_eq!(
+        unrolled_list.iter().copied().collect::<Vec<_>>(),
+        [300, 0, 100, 1, 2, 3, 4, 5, 6, 7, 8, 9, 200]
+    );
+    assert_eq!(unrolled_list.iter().len(), 13);
+
+    assert_eq!(unrolled_list.remove_at(2), Some(100));
+    assert_eq!(unrolled_list.remove_at(12), None);
+    assert_eq!(unrolled_list.pop_front(), Some(300));
+    assert_eq!(unrolled_list.pop_back(), Some(200));
+    assert_eq!(unrolled_list, (0..10).collect());
+
+    // emptied nodes are freed and reused, sparse neighbours are merged
+    while unrolled_list.len() > 2 {
+        unrolled_list.remove_at(1);
+    }
+    assert_eq!(unrolled_list.iter().copied().collect::<Vec<_>>(), [0, 9]);
+    assert_eq!(unrolled_list.node_count(), 1);
+    unrolled_list.push_front(1);
+    unrolled_list.remove_at(0);
+    assert_eq!(unrolled_list.node_count(), 1);
+    unrolled_list.extend(10..14);
+    assert_eq!(unrolled_list.node_count(), 2);
+    assert_eq!(unrolled_list[5], 13);
+
+    for element in unrolled_list.iter_mut() {
+        *element *= 2;
+    }
+    unrolled_list[0] = 1;
+    assert_eq!(format!("{unrolled_list:?}"), "[1, 18, 20, 22, 24, 26]");
+
+    while unrolled_list.pop_back().is_some() {}
+    assert!(unrolled_list.is_empty());
+    assert_eq!(unrolled_list.node_count(), 0);
+    unrolled_list.push_back(7);
+    assert_eq!(unrolled_list.iter().collect::<Vec<_>>(), [&7]);
+}
+
+impl<const N: usize> PositionalList for UnrolledList<u64, N> {
+    fn insert_before(&mut self, position: usize, element: u64) {
+        UnrolledList::insert_before(self, position, element);
+    }
+
+    fn remove_at(&mut self, position: usize) -> Option<u64> {
+        UnrolledList::remove_at(self, position)
+    }
+
+    fn len(&self) -> usize {
+        UnrolledList::len(self)
+    }
+}
+
+#[test]
+fn tc1_matches_vec() {
+    let mut unrolled_list: UnrolledList<u64, 3> = UnrolledList::default();
+    let expected = check_random_positional_ops(&mut unrolled_list, 7, 2000);
+    assert!(unrolled_list.iter().eq(expected.iter()));
+    assert!((0..expected.len()).all(|index| unrolled_list[index] == expected[index]));
+    assert_eq!(unrolled_list.clone(), unrolled_list);
+}
+
+#[test]
+fn tc2_cursor() {
+    let mut unrolled_list: UnrolledList<i32, 2> = UnrolledList::from([1, 2, 3, 4, 5]);
+
+    let mut cursor = unrolled_list.cursor_mut();
+    assert_eq!(cursor.current(), None);
+    assert_eq!(cursor.len(), 5);
+    assert_eq!(cursor.next(), Some(1));
+    // removing every even element while walking
+    while let Some(element) = cursor.next() {
+        if element % 2 == 0 {
+            cursor.remove_current();
+        }
+    }
+    assert_eq!(cursor.current(), Some(&5));
+    cursor.insert_after(6);
+    cursor.insert_before(4);
+    assert_eq!(cursor.current(), Some(&5));
+    assert_eq!(cursor.next(), Some(6));
+    assert_eq!(cursor.next(), None);
+    assert_eq!(
+        unrolled_list.iter().copied().collect::<Vec<_>>(),
+        [1, 3, 4, 5, 6]
+    );
+
+    let mut cursor = unrolled_list.pos_mut(1).unwrap();
+    *cursor.current_mut().unwrap() = 30;
+    assert_eq!(cursor.prev(), Some(1));
+    assert_eq!(cursor.prev(), None);
+    cursor.insert_after(0);
+    assert_eq!(cursor.next(), Some(0));
+    assert_eq!(cursor.remove_current(), Some(0));
+    assert_eq!(cursor.current(), None);
+    assert!(unrolled_list.pos_mut(5).is_none());
+    assert_eq!(
+        unrolled_list.iter().copied().collect::<Vec<_>>(),
+        [1, 30, 4, 5, 6]
+    );
+
+    // both ends meet, also after the list was changed in between
+    let mut cursor = unrolled_list.pos_mut(0).unwrap();
+    assert_eq!(cursor.next_back(), Some(6));
+    assert_eq!(cursor.next(), Some(30));
+    cursor.remove_current();
+    cursor.insert_after(20);
+    assert_eq!(cursor.next_back(), Some(5));
+    assert_eq!(cursor.len(), 2);
+    assert_eq!(cursor.collect::<Vec<_>>(), [20, 4]);
+    assert_eq!(
+        unrolled_list.iter().copied().collect::<Vec<_>>(),
+        [1, 20, 4, 5, 6]
+    );
+    assert_eq!(
+        unrolled_list.cursor_mut().rev().collect::<Vec<_>>(),
+        [6, 5, 4, 20, 1]
+    );
+}
+
+#[test]
+#[should_panic(expected = "index 4 out of range for list of length 3")]
+fn tc3_insert_out_of_range() {
+    let mut unrolled_list: UnrolledList<i32> = UnrolledList::from([1, 2, 3]);
+    unrolled_list.insert_before(4, 0);
+}
+
+#[test]
+fn tc4_inline_elements_dropped_once() {
+    use std::rc::Rc;
+
+    // the node stores the bare elements next to its length and links
+    assert_eq!(
+        std::mem::size_of::<UnrolledNode<f64, 16>>(),
+        16 * std::mem::size_of::<f64>() + 3 * std::mem::size_of::<usize>()
+    );
+
+    let counted = Rc::new(());
+    let mut unrolled_list: UnrolledList<Rc<()>, 4> = (0..10).map(|_| Rc::clone(&counted)).collect();
+    // splits and merges move the elements without dropping or duplicating them
+    unrolled_list.insert_before(1, Rc::clone(&counted));
+    while unrolled_list.len() > 3 {
+        unrolled_list.remove_at(1);
+    }
+    assert_eq!(Rc::strong_count(&counted), 4);
+
+    let cloned_list = unrolled_list.clone();
+    assert_eq!(Rc::strong_count(&counted), 7);
+    drop(cloned_list);
+    unrolled_list.push_front(Rc::clone(&counted));
+    unrolled_list.clear();
+    assert_eq!(Rc::strong_count(&counted), 1);
+}
+
+/// Checks that all nodes but a single one hold at least half of `N` elements.
+fn assert_half_full<T, const N: usize>(unrolled_list: &UnrolledList<T, N>) {
+    if unrolled_list.node_count() < 2 {
+        return;
+    }
+    let mut node_index = unrolled_list.head_index;
+    loop {
+        let node = &unrolled_list.all_nodes[node_index];
+        assert!(node.len() >= N / 2, "node with {} elements", node.len());
+        if node_index == unrolled_list.tail_index {
+            break;
+        }
+        node_index = node.next_index;
+    }
+}
+
+#[test]
+fn tc5_removals_keep_nodes_half_full() {
+    let mut unrolled_list: UnrolledList<u64, 8> = (0..256).collect();
+    let mut expected: Vec<u64> = (0..256).collect();
+    let mut state = 11u64;
+    while !expected.is_empty() {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let position = (state >> 33) as usize % expected.len();
+        // removals at both ends, at random positions and through a cursor,
+        // some insertions in between split nodes
+        match (state >> 20) % 6 {
+            0 => assert_eq!(unrolled_list.pop_front(), Some(expected.remove(0))),
+            1 => assert_eq!(unrolled_list.pop_back(), expected.pop()),
+            2 => assert_eq!(
+                unrolled_list.remove_at(position),
+                Some(expected.remove(position))
+            ),
+            3 => {
+                let mut cursor = unrolled_list.pos_mut(position).unwrap();
+                assert_eq!(cursor.remove_current(), Some(expected.remove(position)));
+                if let Some(next_element) = cursor.next() {
+                    assert_eq!(next_element, expected[position]);
+                    assert_eq!(cursor.remove_current(), Some(expected.remove(position)));
+                }
+            }
+            4 => {
+                unrolled_list.insert_before(position, state);
+                expected.insert(position, state);
+            }
+            _ => {
+                let mut cursor = unrolled_list.pos_mut(position).unwrap();
+                cursor.insert_after(state);
+                expected.insert(position + 1, state);
+            }
+        }
+        assert_half_full(&unrolled_list);
+        assert!(unrolled_list.iter().eq(expected.iter()));
+    }
+    assert_eq!(unrolled_list.node_count(), 0);
+}
@@ -14,7 +14,7 @@ fn main() -> Result<(), ()> {
 
     // use map for formatting the poly string
     let poly_as_str: String = poly_series
-        .iter()
+        .iter_ref()
         .map(|x| {
             let coeff = x.coefficient;
             let order = x.order;
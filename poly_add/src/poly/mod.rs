@@ -2,7 +2,10 @@ use std::{
     ops::{Add, BitXor, Mul}
 };
 
-use container::List;
+use container::{List, SortedList};
+
+/// A sum of polynomial terms, ordered by ascending order
+pub type Series = SortedList<Polynomial, i32>;
 
 #[derive(Default)]
 pub struct Polynomial {
@@ -24,42 +27,36 @@ impl Polynomial {
             order: self.order * order,
         }
     }
+
+    pub fn into_series(self) -> Series {
+        let mut series: Series = SortedList::new(|term| term.order);
+        series.insert_sorted(self);
+        series
+    }
 }
 
 impl Add for Polynomial {
-    type Output = container::List<Polynomial>;
+    type Output = Series;
 
     fn add(self, rhs: Self) -> Self::Output {
-        if rhs.order == self.order {
-            return container::List::from_array([Polynomial {
-                coefficient: self.coefficient + rhs.coefficient,
-                order: self.order,
-            }]);
-        }
+        let mut series = self.into_series();
+        series.merge_with(rhs.into_series(), |term, other_term| Polynomial {
+            coefficient: term.coefficient + other_term.coefficient,
+            order: term.order,
+        });
+        series
+    }
+}
 
-        if self.order > rhs.order {
-            return container::List::from_array([
-                Polynomial {
-                    coefficient: rhs.coefficient,
-                    order: rhs.order,
-                },
-                Polynomial {
-                    coefficient: self.coefficient,
-                    order: self.order,
-                },
-            ]);
-        }
+impl Add<Polynomial> for Series {
+    type Output = Series;
 
-        container::List::from_array([
-            Polynomial {
-                coefficient: self.coefficient,
-                order: self.order,
-            },
-            Polynomial {
-                coefficient: rhs.coefficient,
-                order: rhs.order,
-            },
-        ])
+    fn add(mut self, rhs: Polynomial) -> Self::Output {
+        self.merge_with(rhs.into_series(), |term, other_term| Polynomial {
+            coefficient: term.coefficient * other_term.coefficient,
+            order: term.order,
+        });
+        self
     }
 }
 